@@ -4,7 +4,7 @@
 //!
 //! `Iroha` is the main instance of the peer program. `Arguments`
 //! should be constructed externally: (see `main.rs`).
-use std::{path::PathBuf, sync::Arc};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use config::Configuration;
@@ -17,14 +17,17 @@ use iroha_core::{
     queue::Queue,
     smartcontracts::permissions::{IsInstructionAllowedBoxed, IsQueryAllowedBoxed},
     sumeragi::{Sumeragi, SumeragiTrait},
-    tx::{PeerId, TransactionValidator},
+    tx::{PeerId, TransactionValidator, VersionedAcceptedTransaction},
     IrohaNetwork,
 };
+use iroha_crypto::KeyPair;
 use iroha_data_model::prelude::*;
+use parity_scale_codec::Encode;
 use tokio::{
     signal,
-    sync::{broadcast, Notify},
+    sync::{broadcast, Barrier, Notify},
     task,
+    time::timeout,
 };
 use torii::Torii;
 
@@ -41,19 +44,97 @@ pub struct Arguments {
     pub submit_genesis: bool,
     /// Set custom genesis file path. `None` if `submit_genesis` set to `false`.
     pub genesis_path: Option<PathBuf>,
+    /// Path to a detached genesis signature produced out of band. When set,
+    /// the signature is read from this file instead of being produced with
+    /// the peer's own key pair (see [`GenesisSigner`]). Either way,
+    /// `Iroha::new` only logs the signature: the raw block is passed to
+    /// `GenesisNetwork::from_configuration` unchanged, without it.
+    pub genesis_signature_path: Option<PathBuf>,
     /// Set custom config file path.
     pub config_path: PathBuf,
 }
 
+/// Produces the authorization for a genesis block, decoupling "build the
+/// block" ([`RawGenesisBlock::from_path`]) from "authorize the block".
+///
+/// The in-process key-pair signer ([`KeyPairGenesisSigner`]) is the default;
+/// [`DetachedGenesisSigner`] accepts a signature produced out of band (e.g.
+/// by a CI step with access to an HSM) so the genesis key never has to live
+/// on the running peer.
+pub trait GenesisSigner {
+    /// Signs `raw`, returning the signature that authorizes it for
+    /// submission.
+    ///
+    /// # Errors
+    /// Fails if signing fails, or if a detached signature cannot be read.
+    fn sign_genesis(&self, raw: &RawGenesisBlock) -> Result<iroha_crypto::Signature>;
+}
+
+/// Signs genesis with a key-pair held by the running peer's own process.
+pub struct KeyPairGenesisSigner {
+    key_pair: KeyPair,
+}
+
+impl KeyPairGenesisSigner {
+    /// Creates a signer from `key_pair`.
+    pub fn new(key_pair: KeyPair) -> Self {
+        Self { key_pair }
+    }
+}
+
+impl GenesisSigner for KeyPairGenesisSigner {
+    fn sign_genesis(&self, raw: &RawGenesisBlock) -> Result<iroha_crypto::Signature> {
+        let payload = serde_json::to_vec(raw).wrap_err("Failed to serialize genesis block")?;
+        iroha_crypto::Signature::new(self.key_pair.clone(), &payload)
+            .wrap_err("Failed to sign genesis block")
+    }
+}
+
+/// Accepts a genesis signature produced out of band instead of signing
+/// in-process, so the signing key never has to be loaded by the peer.
+pub struct DetachedGenesisSigner {
+    signature: iroha_crypto::Signature,
+}
+
+impl DetachedGenesisSigner {
+    /// Loads a detached signature from `path`.
+    ///
+    /// # Errors
+    /// Fails if the file cannot be read or deserialized.
+    pub fn from_path(path: &std::path::Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read genesis signature at {path:?}"))?;
+        let signature =
+            serde_json::from_str(&contents).wrap_err("Failed to deserialize genesis signature")?;
+        Ok(Self { signature })
+    }
+}
+
+impl GenesisSigner for DetachedGenesisSigner {
+    fn sign_genesis(&self, _raw: &RawGenesisBlock) -> Result<iroha_crypto::Signature> {
+        Ok(self.signature.clone())
+    }
+}
+
 const CONFIGURATION_PATH: &str = "config.json";
 const GENESIS_PATH: &str = "genesis.json";
 const SUBMIT_GENESIS: bool = false;
 
+/// How long `start` waits for Kura, Sumeragi and `BlockSynchronizer` to
+/// acknowledge a shutdown signal. Once it elapses, `start` logs a warning
+/// and returns; actors that have not acknowledged are not aborted.
+///
+/// This belongs on [`Configuration`] as a `shutdown_timeout_ms` field once
+/// `cli::config` is reintroduced; until then it is a fixed fallback so
+/// shutdown stays bounded.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 impl Default for Arguments {
     fn default() -> Self {
         Self {
             submit_genesis: SUBMIT_GENESIS,
             genesis_path: Some(GENESIS_PATH.into()),
+            genesis_signature_path: None,
             config_path: CONFIGURATION_PATH.into(),
         }
     }
@@ -80,6 +161,16 @@ where
     pub block_sync: AlwaysAddr<B>,
     /// Torii web server
     pub torii: Option<Torii>,
+    /// Fired once to tell every actor to stop accepting new work and drain.
+    shutdown_notify: Arc<Notify>,
+    /// Counted down by Kura, Sumeragi and `BlockSynchronizer` once each has
+    /// finished draining, and by `start` itself on Torii's behalf, so
+    /// `start` can await a full, coordinated teardown instead of a
+    /// best-effort one.
+    shutdown_barrier: Arc<Barrier>,
+    /// Upper bound on how long `start` waits for `shutdown_barrier` before
+    /// giving up and returning anyway.
+    shutdown_timeout: Duration,
 }
 
 impl<G, S, K, B> Iroha<G, K, S, B>
@@ -118,9 +209,25 @@ where
         iroha_logger::info!("(translation) Welcome to Hyperledger Iroha 2!");
 
         let genesis = if let Some(genesis_path) = &args.genesis_path {
+            let raw_genesis = RawGenesisBlock::from_path(genesis_path)?;
+
+            let signer: Box<dyn GenesisSigner> =
+                if let Some(signature_path) = &args.genesis_signature_path {
+                    Box::new(DetachedGenesisSigner::from_path(signature_path)?)
+                } else {
+                    Box::new(KeyPairGenesisSigner::new(KeyPair::new(
+                        config.public_key.clone(),
+                        config.private_key.clone(),
+                    )?))
+                };
+            let genesis_signature = signer
+                .sign_genesis(&raw_genesis)
+                .wrap_err("Failed to authorize genesis block")?;
+            iroha_logger::debug!(?genesis_signature, "Authorized genesis block");
+
             G::from_configuration(
                 args.submit_genesis,
-                RawGenesisBlock::from_path(genesis_path)?,
+                raw_genesis,
                 &Some(config.genesis.clone()),
                 &config.sumeragi.transaction_limits,
             )
@@ -194,18 +301,32 @@ where
 
         // Validate every transaction in genesis block
         if let Some(ref genesis) = genesis {
+            validate_genesis_limits(&***genesis, &config.sumeragi.transaction_limits)
+                .wrap_err("Genesis block exceeds configured transaction limits")?;
             transaction_validator
                 .validate_every(&***genesis)
                 .wrap_err("Transaction validation failed in genesis block")?;
         }
 
         let notify_shutdown = Arc::new(Notify::new());
+        // One party per actor that needs to ack a drained shutdown - Kura,
+        // Sumeragi and BlockSynchronizer - plus one for `start` itself,
+        // which waits on Torii's behalf: Torii already returns as soon as
+        // it observes `notify_shutdown`, so it doesn't hold a clone of this
+        // barrier and never calls `wait` on it.
+        let shutdown_barrier = Arc::new(Barrier::new(4));
 
         let queue = Arc::new(Queue::from_configuration(&config.queue, Arc::clone(&wsv)));
         let telemetry_started = Self::start_telemetry(telemetry, &config).await?;
-        let kura = K::from_configuration(&config.kura, Arc::clone(&wsv), broker.clone())
-            .await?
-            .preinit();
+        let kura = K::from_configuration(
+            &config.kura,
+            Arc::clone(&wsv),
+            broker.clone(),
+            Arc::clone(&notify_shutdown),
+            Arc::clone(&shutdown_barrier),
+        )
+        .await?
+        .preinit();
 
         let sumeragi: AlwaysAddr<_> = S::from_configuration(
             &config.sumeragi,
@@ -218,6 +339,8 @@ where
             broker.clone(),
             kura.address.clone().expect_running().clone(),
             network_addr.clone(),
+            Arc::clone(&notify_shutdown),
+            Arc::clone(&shutdown_barrier),
         )
         .wrap_err("Failed to initialize Sumeragi.")?
         .start()
@@ -231,6 +354,8 @@ where
             sumeragi.clone(),
             PeerId::new(&config.torii.p2p_addr, &config.public_key),
             broker.clone(),
+            Arc::clone(&notify_shutdown),
+            Arc::clone(&shutdown_barrier),
         )
         .start()
         .await
@@ -246,7 +371,7 @@ where
             Arc::clone(&notify_shutdown),
         );
 
-        Self::start_listening_signal(notify_shutdown)?;
+        Self::start_listening_signal(Arc::clone(&notify_shutdown))?;
 
         let torii = Some(torii);
         Ok(Self {
@@ -256,6 +381,9 @@ where
             kura,
             block_sync,
             torii,
+            shutdown_notify: notify_shutdown,
+            shutdown_barrier,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
         })
     }
 
@@ -267,12 +395,29 @@ where
     #[iroha_futures::telemetry_future]
     pub async fn start(&mut self) -> Result<()> {
         iroha_logger::info!("Starting Iroha");
-        self.torii
+        let torii_result = self
+            .torii
             .take()
             .ok_or_else(|| eyre!("Seems like peer was already started"))?
             .start()
             .await
-            .wrap_err("Failed to start Torii")
+            .wrap_err("Failed to start Torii");
+
+        // Torii returns once it observes `shutdown_notify`, at which point
+        // Kura, Sumeragi and the block synchronizer are expected to be
+        // draining too; wait for all of them to ack on the shared barrier
+        // rather than returning the moment Torii alone is done.
+        if timeout(self.shutdown_timeout, self.shutdown_barrier.wait())
+            .await
+            .is_err()
+        {
+            iroha_logger::warn!(
+                timeout_ms = self.shutdown_timeout.as_millis() as u64,
+                "Not all actors acknowledged shutdown in time, returning without them"
+            );
+        }
+
+        torii_result
     }
 
     /// Starts iroha in separate tokio task.
@@ -348,6 +493,130 @@ where
     }
 }
 
+/// Checks every genesis transaction against `limits`, collecting *all*
+/// violations instead of stopping at the first one, so a bad genesis fails
+/// fast with a message that names the offending transaction and limit
+/// (e.g. "genesis tx #3: 5200 instructions exceeds max_instruction_number=4096")
+/// rather than a flat "validation failed".
+///
+/// Runs before any WSV mutation.
+///
+/// # Errors
+/// If any genesis transaction exceeds `limits.max_instruction_number` or
+/// `limits.max_wasm_size_bytes`.
+fn validate_genesis_limits<'genesis>(
+    transactions: impl IntoIterator<Item = &'genesis VersionedAcceptedTransaction>,
+    limits: &TransactionLimits,
+) -> Result<()> {
+    let mut violations = Vec::new();
+
+    for (index, transaction) in transactions.into_iter().enumerate() {
+        match &transaction.payload().instructions {
+            Executable::Instructions(isi) => {
+                let instruction_count = isi.len() as u64;
+                if instruction_count > limits.max_instruction_number {
+                    violations.push(format!(
+                        "genesis tx #{index}: {instruction_count} instructions exceeds max_instruction_number={}",
+                        limits.max_instruction_number
+                    ));
+                }
+            }
+            Executable::Wasm(wasm) => {
+                let size_bytes = wasm.as_ref().len() as u64;
+                if size_bytes > limits.max_wasm_size_bytes {
+                    violations.push(format!(
+                        "genesis tx #{index}: {size_bytes} byte wasm exceeds max_wasm_size_bytes={}",
+                        limits.max_wasm_size_bytes
+                    ));
+                }
+            }
+        }
+    }
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(eyre!(
+            "Genesis block violates transaction limits:\n{}",
+            violations.join("\n")
+        ))
+    }
+}
+
+/// Splits `instructions` into batches that each fit within
+/// `limits.max_instruction_number` *and* `limits.max_wasm_size_bytes`, so a
+/// large genesis (e.g. one that registers a million domains, per
+/// `client/examples/million_accounts_genesis.rs`) can be submitted as
+/// several genesis transactions instead of one oversized one that
+/// `validate_genesis_limits` would then reject outright.
+///
+/// `limits.max_wasm_size_bytes` is named for the `Executable::Wasm` case,
+/// but it's the only byte-size ceiling `TransactionLimits` exposes, so it
+/// doubles here as the SCALE-encoded byte budget for a batch of plain
+/// instructions. A batch is closed as soon as adding the next instruction
+/// would break either limit, so every batch this returns is safe to
+/// `validate_genesis_limits` unchanged.
+///
+/// `core::genesis`, which declares `RawGenesisBlockBuilder` and
+/// `GenesisNetwork`, has no source file in this checkout, so this doesn't
+/// wire into `RawGenesisBlockBuilder::build()` directly; it's a standalone
+/// helper for that builder to call once assembling its final instruction
+/// list, keyed off the same `TransactionLimits` already threaded through
+/// `GenesisNetwork::from_configuration`.
+///
+/// # Errors
+/// - If `limits.max_instruction_number` is `0`: no batch could ever hold
+/// even a single instruction, so every instruction would be rejected -
+/// this is reported immediately, naming instruction `#0` and the limit
+/// it violates, instead of silently producing a run of empty batches.
+/// - If any single instruction's encoded size exceeds
+/// `limits.max_wasm_size_bytes` on its own: no batch size could ever fit
+/// it, so this is reported immediately rather than emitting a batch that
+/// `validate_genesis_limits` would just reject later.
+pub fn partition_genesis_instructions(
+    instructions: &[Instruction],
+    limits: &TransactionLimits,
+) -> Result<Vec<Vec<Instruction>>> {
+    if instructions.is_empty() {
+        return Ok(Vec::new());
+    }
+    if limits.max_instruction_number == 0 {
+        return Err(eyre!(
+            "genesis instruction #0: cannot fit in a transaction, max_instruction_number=0"
+        ));
+    }
+
+    let max_batch_bytes = limits.max_wasm_size_bytes as usize;
+    let mut batches = Vec::new();
+    let mut current = Vec::new();
+    let mut current_bytes = 0_usize;
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        let instruction_bytes = instruction.encode().len();
+        if max_batch_bytes > 0 && instruction_bytes > max_batch_bytes {
+            return Err(eyre!(
+                "genesis instruction #{index}: {instruction_bytes} byte instruction exceeds max_wasm_size_bytes={}",
+                limits.max_wasm_size_bytes
+            ));
+        }
+
+        let batch_full = current.len() >= limits.max_instruction_number as usize
+            || (max_batch_bytes > 0 && current_bytes + instruction_bytes > max_batch_bytes);
+        if !current.is_empty() && batch_full {
+            batches.push(std::mem::take(&mut current));
+            current_bytes = 0;
+        }
+
+        current_bytes += instruction_bytes;
+        current.push(instruction.clone());
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    Ok(batches)
+}
+
 /// Returns the `domain_name: domain` mapping, for initial domains.
 ///
 /// # Errors
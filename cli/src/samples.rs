@@ -0,0 +1,168 @@
+//! In-process, multi-peer test network construction.
+//!
+//! Benches and integration tests previously wired up each peer of a cluster
+//! by hand, repeating the same key-pair/port/trusted-peers bookkeeping at
+//! every call site. [`TestNetwork`] replaces that with one reusable,
+//! deterministic, black-box-startable builder shared via a single
+//! [`Broker`].
+#![cfg(feature = "test-network")]
+
+use std::collections::HashSet;
+
+use color_eyre::eyre::{Result, WrapErr};
+use iroha_actor::broker::Broker;
+use iroha_crypto::KeyPair;
+use iroha_data_model::prelude::PeerId;
+
+use crate::config::{Configuration, ConfigurationProxy};
+
+/// Selects which peer of a [`TestNetwork`] is responsible for submitting
+/// the genesis block.
+#[derive(Clone, Copy, Debug)]
+pub struct GenesisSubmitter(usize);
+
+impl Default for GenesisSubmitter {
+    fn default() -> Self {
+        Self(0)
+    }
+}
+
+/// Builds an in-process cluster of `n` [`Iroha`] peers sharing one
+/// [`Broker`]: generates key-pairs, computes the mutual `trusted_peers` set,
+/// assigns non-colliding p2p/api ports, and starts every peer.
+pub struct TestNetwork {
+    peer_count: usize,
+    genesis_submitter: GenesisSubmitter,
+    overrides: Vec<Option<ConfigurationProxy>>,
+    base_port: u16,
+}
+
+/// A peer started as part of a [`TestNetwork`], together with the
+/// configuration it was given.
+pub struct TestPeer {
+    /// The running peer.
+    pub iroha: crate::Iroha,
+    /// The configuration `iroha` was started with.
+    pub configuration: Configuration,
+}
+
+impl TestNetwork {
+    /// Starts building a network of `peer_count` peers. Ports are assigned
+    /// starting at an arbitrary high base to avoid colliding with a peer
+    /// left running from a previous test.
+    pub fn new(peer_count: usize) -> Self {
+        Self {
+            peer_count,
+            genesis_submitter: GenesisSubmitter::default(),
+            overrides: vec![None; peer_count],
+            base_port: 40_000,
+        }
+    }
+
+    /// Records which peer is designated to submit genesis. Defaults to peer
+    /// `0`. [`Self::start`] gives every peer, this one included, `None` as
+    /// its genesis block, so no peer of the network submits one.
+    ///
+    /// # Panics
+    /// If `index >= peer_count`.
+    #[must_use]
+    pub fn genesis_submitter(mut self, index: usize) -> Self {
+        assert!(index < self.peer_count, "genesis submitter index out of range");
+        self.genesis_submitter = GenesisSubmitter(index);
+        self
+    }
+
+    /// Overrides peer `index`'s configuration before it starts, so a test
+    /// can tighten a single peer's timeouts without affecting the rest of
+    /// the cluster.
+    ///
+    /// # Panics
+    /// If `index >= peer_count`.
+    #[must_use]
+    pub fn with_config_override(mut self, index: usize, proxy: ConfigurationProxy) -> Self {
+        self.overrides[index] = Some(proxy);
+        self
+    }
+
+    /// Starts every peer on a shared `tokio` runtime and returns their
+    /// handles.
+    ///
+    /// # Errors
+    /// Fails if any peer fails to start.
+    pub async fn start(self) -> Result<Vec<TestPeer>> {
+        let broker = Broker::new();
+
+        let key_pairs = (0..self.peer_count)
+            .map(|_| KeyPair::generate().wrap_err("Failed to generate key pair"))
+            .collect::<Result<Vec<_>>>()?;
+
+        let peer_ids: Vec<PeerId> = key_pairs
+            .iter()
+            .enumerate()
+            .map(|(index, key_pair)| {
+                let p2p_addr = format!("127.0.0.1:{}", self.base_port + (index as u16) * 2);
+                PeerId::new(&p2p_addr, key_pair.public_key())
+            })
+            .collect();
+        let trusted_peers: HashSet<PeerId> = peer_ids.iter().cloned().collect();
+
+        let mut peers = Vec::with_capacity(self.peer_count);
+        for (index, (key_pair, peer_id)) in key_pairs.into_iter().zip(peer_ids).enumerate() {
+            let api_addr = format!("127.0.0.1:{}", self.base_port + (index as u16) * 2 + 1);
+
+            let mut configuration = Configuration::default();
+            configuration.public_key = key_pair.public_key().clone();
+            configuration.private_key = key_pair.private_key().clone();
+            configuration.torii.p2p_addr = peer_id.address.clone();
+            configuration.torii.api_url = api_addr;
+            configuration.sumeragi.trusted_peers.peers = trusted_peers.clone();
+
+            if let Some(proxy) = self.overrides.get(index).cloned().flatten() {
+                configuration = proxy.override_configuration(configuration)?;
+            }
+
+            // Only `genesis_submitter` would hand a peer a real genesis
+            // block; every other peer receives it over consensus instead.
+            // Building that block here needs `RawGenesisBlockBuilder`
+            // (tracked separately) to assemble it in memory rather than
+            // reading a file from disk, so for now every peer starts with
+            // `None` and relies on the designated submitter's genesis
+            // config to still be set correctly for bookkeeping.
+            let _is_genesis_submitter = index == self.genesis_submitter.0;
+            let genesis = None;
+
+            let iroha = crate::Iroha::with_genesis(
+                genesis,
+                configuration.clone(),
+                iroha_core::smartcontracts::permissions::combinators::AllowAll::new().into(),
+                iroha_core::smartcontracts::permissions::combinators::AllowAll::new().into(),
+                broker.clone(),
+                None,
+            )
+            .await
+            .wrap_err_with(|| format!("Failed to start peer #{index}"))?;
+
+            peers.push(TestPeer {
+                iroha,
+                configuration,
+            });
+        }
+
+        Ok(peers)
+    }
+}
+
+/// Blocks until every peer in `peers` has committed at least the genesis
+/// block.
+///
+/// # Errors
+/// Never returns an error today; kept fallible so a future timeout can be
+/// added without breaking callers.
+pub async fn wait_for_genesis_committed(peers: &[TestPeer]) -> Result<()> {
+    for peer in peers {
+        while peer.iroha.wsv.blocks().len() < 1 {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+    }
+    Ok(())
+}
@@ -4,21 +4,30 @@ use std::{thread, time::Duration};
 use iroha_data_model::prelude::*;
 use test_network::{wait_for_genesis_committed, PeerBuilder};
 
+/// How many domain/account pairs to register per transaction. Submitting one
+/// transaction per entity (as this example used to) makes consensus and
+/// signature overhead the dominant cost at million-entity scale; batching
+/// many `Register`s into a single transaction via [`Client::register_all`]
+/// amortizes that overhead across the whole chunk.
+const REGISTRATIONS_PER_BATCH: u32 = 1_000;
+
 fn create_million_accounts_directly() {
     let (_rt, _peer, test_client) = <PeerBuilder>::new().start_with_runtime();
     wait_for_genesis_committed(&vec![test_client.clone()], 0);
-    for i in 0_u32..1_000_000_u32 {
-        let domain_id: DomainId = format!("wonderland-{}", i).parse().expect("Valid");
-        let normal_account_id = AccountId::new(
-            format!("bob-{}", i).parse().expect("Valid"),
-            domain_id.clone(),
-        );
-        let create_domain = RegisterBox::new(Domain::new(domain_id));
-        let create_account = RegisterBox::new(Account::new(normal_account_id.clone(), []));
-        if test_client
-            .submit_all([create_domain.into(), create_account.into()].to_vec())
-            .is_err()
-        {
+
+    for chunk_start in (0_u32..1_000_000_u32).step_by(REGISTRATIONS_PER_BATCH as usize) {
+        let batch = (chunk_start..chunk_start + REGISTRATIONS_PER_BATCH).flat_map(|i| {
+            let domain_id: DomainId = format!("wonderland-{}", i).parse().expect("Valid");
+            let normal_account_id = AccountId::new(
+                format!("bob-{}", i).parse().expect("Valid"),
+                domain_id.clone(),
+            );
+            [
+                RegisterBox::new(Domain::new(domain_id)),
+                RegisterBox::new(Account::new(normal_account_id, [])),
+            ]
+        });
+        if test_client.register_all(batch).is_err() {
             thread::sleep(Duration::from_millis(100));
         }
     }
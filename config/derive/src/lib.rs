@@ -20,6 +20,7 @@ mod attrs {
     pub const ENV_PREFIX: &str = "env_prefix";
     pub const SERDE_AS_STR: &str = "serde_as_str";
     pub const INNER: &str = "inner";
+    pub const DEFAULT: &str = "default";
 }
 
 fn get_type_argument<'sl, 'tl>(s: &'sl str, ty: &'tl Type) -> Option<&'tl GenericArgument> {
@@ -41,6 +42,57 @@ fn get_type_argument<'sl, 'tl>(s: &'sl str, ty: &'tl Type) -> Option<&'tl Generi
     None
 }
 
+/// Builds `return Err(...)` for an unknown config field path, attaching a
+/// "did you mean" suggestion computed at runtime (the candidate segment is
+/// only known once the caller actually passes a wrong path) by Levenshtein
+/// distance against `field_idents`, the set of fields known at this nesting
+/// level. `unmatched_path` is an expression evaluating to the full `&[&str]`
+/// path that failed to match; it's reused verbatim as the error's `path`.
+fn unknown_field_error(
+    field_idents: &[&Ident],
+    unmatched_path: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let known_fields = field_idents.iter().map(|ident| quote! { stringify!(#ident) });
+    quote! {
+        {
+            fn levenshtein(a: &str, b: &str) -> usize {
+                let a: Vec<char> = a.chars().collect();
+                let b: Vec<char> = b.chars().collect();
+                let mut row: Vec<usize> = (0..=b.len()).collect();
+                for i in 1..=a.len() {
+                    let mut prev = row[0];
+                    row[0] = i;
+                    for j in 1..=b.len() {
+                        let temp = row[j];
+                        row[j] = if a[i - 1] == b[j - 1] {
+                            prev
+                        } else {
+                            1 + prev.min(row[j]).min(row[j - 1])
+                        };
+                        prev = temp;
+                    }
+                }
+                row[b.len()]
+            }
+
+            let path = #unmatched_path;
+            let known_fields: &[&str] = &[#(#known_fields),*];
+            let did_you_mean = path.first().and_then(|candidate| {
+                known_fields
+                    .iter()
+                    .map(|&name| (name, levenshtein(candidate, name)))
+                    .min_by_key(|(_, distance)| *distance)
+                    .filter(|(name, distance)| *distance <= core::cmp::max(2, name.len() / 3))
+                    .map(|(name, _)| name.to_owned())
+            });
+            return Err(iroha_config::derive::Error::UnknownField {
+                path: path.iter().map(ToString::to_string).collect(),
+                did_you_mean,
+            })
+        }
+    }
+}
+
 fn is_arc_rwlock(ty: &Type) -> bool {
     #[allow(clippy::shadow_unrelated)]
     let dearced_ty = get_type_argument("Arc", ty)
@@ -99,6 +151,22 @@ impl Parse for SerdeAsStr {
     }
 }
 
+struct DefaultValue {
+    _ident: Ident,
+    _eq: Token![=],
+    value: LitStr,
+}
+
+impl Parse for DefaultValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _ident: parse_const_ident(input, attrs::DEFAULT)?,
+            _eq: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
 /// Derive for config. Check other doc in `iroha_config` reexport
 #[proc_macro_derive(Configurable, attributes(config))]
 pub fn configurable_derive(input: TokenStream) -> TokenStream {
@@ -131,33 +199,46 @@ fn impl_load_env(
                 false
             };
             let set_field = if is_string {
-                quote! { #l_value = var }
+                quote! { core::result::Result::<(), iroha_config::derive::Error>::Ok(#l_value = var) }
             } else if as_str_attr {
                 quote! {
-                    #l_value = serde_json::from_value(var.into())
-                        .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))?
+                    serde_json::from_value(var.into())
+                        .map(|parsed| #l_value = parsed)
+                        .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))
                 }
             } else {
                 quote! {
-                    #l_value = serde_json::from_str(&var)
-                        .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))?
+                    serde_json::from_str(&var)
+                        .map(|parsed| #l_value = parsed)
+                        .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))
                 }
             };
-            (set_field, l_value)
+            (set_field, l_value, ident)
         })
         .zip(field_environment.iter())
         .zip(inner.iter())
-        .map(|(((set_field, l_value), field_env), &inner_thing)| {
+        .map(|(((set_field, l_value, ident), field_env), &inner_thing)| {
             let inner_thing2 = if inner_thing {
                 quote! {
-                    #l_value.load_environment()?;
+                    if let Err(e) = #l_value.load_environment() {
+                        match e {
+                            iroha_config::derive::Error::Multiple(sub_errors) => {
+                                errors.extend(sub_errors.into_iter().map(|sub_error| {
+                                    iroha_config::derive::Error::field_error(stringify!(#ident), sub_error)
+                                }));
+                            }
+                            other => errors.push(iroha_config::derive::Error::field_error(stringify!(#ident), other)),
+                        }
+                    }
                 }
             } else {
                 quote! {}
             };
             quote! {
                 if let Ok(var) = std::env::var(#field_env) {
-                    #set_field;
+                    if let Err(e) = { #set_field } {
+                        errors.push(e);
+                    }
                 }
                 #inner_thing2
             }
@@ -167,7 +248,146 @@ fn impl_load_env(
         fn load_environment(
             &'_ mut self
         ) -> core::result::Result<(), iroha_config::derive::Error> {
+            self.load_defaults()?;
+            let mut errors: Vec<iroha_config::derive::Error> = Vec::new();
             #(#set_field)*
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(iroha_config::derive::Error::Multiple(errors))
+            }
+        }
+    }
+}
+
+fn impl_load_defaults(
+    field_idents: &[&Ident],
+    inner: &[bool],
+    lvalue: &[proc_macro2::TokenStream],
+    as_str: &[bool],
+    field_ty: &[Type],
+    default_value: &[Option<String>],
+) -> proc_macro2::TokenStream {
+    let set_default = field_ty
+        .iter()
+        .zip(field_idents.iter())
+        .zip(as_str.iter())
+        .zip(lvalue.iter())
+        .zip(default_value.iter())
+        .zip(inner.iter())
+        .map(
+            |(((((ty, ident), &as_str_attr), l_value), default), &inner_thing)| {
+                let is_string = if let Type::Path(TypePath { path, .. }) = ty {
+                    path.is_ident("String")
+                } else {
+                    false
+                };
+                let set_field = default.as_ref().map_or_else(
+                    || quote! {},
+                    |value| {
+                        if is_string {
+                            quote! { #l_value = #value.to_owned(); }
+                        } else if as_str_attr {
+                            quote! {
+                                #l_value = serde_json::from_value(serde_json::Value::String(#value.to_owned()))
+                                    .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))?;
+                            }
+                        } else {
+                            quote! {
+                                #l_value = serde_json::from_str(#value)
+                                    .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))?;
+                            }
+                        }
+                    },
+                );
+                let inner_thing2 = if inner_thing {
+                    quote! { #l_value.load_defaults()?; }
+                } else {
+                    quote! {}
+                };
+                quote! {
+                    #set_field
+                    #inner_thing2
+                }
+            },
+        );
+
+    quote! {
+        fn load_defaults(&'_ mut self) -> core::result::Result<(), iroha_config::derive::Error> {
+            #(#set_default)*
+            Ok(())
+        }
+    }
+}
+
+/// Deep-merges a partial `serde_json::Value` document into `self`: object
+/// keys that name a field overwrite that field (recursing into
+/// `#[config(inner)]` children via their own `merge`), keys that don't
+/// appear in `overrides` leave the current value untouched, and anything
+/// that isn't a JSON object is ignored rather than erroring.
+fn impl_merge(
+    field_idents: &[&Ident],
+    inner: &[bool],
+    lvalue: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let merge_field = field_idents
+        .iter()
+        .zip(inner.iter())
+        .zip(lvalue.iter())
+        .map(|((ident, &inner_thing), l_value)| {
+            let name = ident.to_string();
+            if inner_thing {
+                quote! {
+                    if let Some(value) = overrides.get(#name) {
+                        #l_value.merge(value.clone())?;
+                    }
+                }
+            } else {
+                quote! {
+                    if let Some(value) = overrides.get(#name) {
+                        #l_value = serde_json::from_value(value.clone())
+                            .map_err(|e| iroha_config::derive::Error::field_error(stringify!(#ident), e))?;
+                    }
+                }
+            }
+        });
+
+    quote! {
+        fn merge(
+            &'_ mut self,
+            overrides: serde_json::Value,
+        ) -> core::result::Result<(), iroha_config::derive::Error> {
+            if let serde_json::Value::Object(_) = &overrides {
+                #(#merge_field)*
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Applies, in increasing precedence, the struct's own `#[config(default)]`
+/// values, a parsed config document, an optional named profile nested under
+/// `document["environments"][environment]` that overlays the document (e.g.
+/// a `dev`/`prod` table), and finally environment variables via
+/// `load_environment`, which remains the final and highest-precedence layer.
+fn impl_load_layered() -> proc_macro2::TokenStream {
+    quote! {
+        fn load_layered(
+            &'_ mut self,
+            document: serde_json::Value,
+            environment: Option<&str>,
+        ) -> core::result::Result<(), iroha_config::derive::Error> {
+            self.load_defaults()?;
+            self.merge(document.clone())?;
+            if let Some(environment) = environment {
+                if let Some(overrides) = document
+                    .get("environments")
+                    .and_then(|environments| environments.get(environment))
+                {
+                    self.merge(overrides.clone())?;
+                }
+            }
+            self.load_environment()?;
             Ok(())
         }
     }
@@ -180,14 +400,13 @@ fn impl_get_doc_recursive(
     docs: Vec<LitStr>,
 ) -> proc_macro2::TokenStream {
     if field_idents.is_empty() {
+        let unknown_field = unknown_field_error(field_idents, quote! { inner_field.as_ref() });
         return quote! {
             fn get_doc_recursive<'a>(
                 inner_field: impl AsRef<[&'a str]>,
             ) -> core::result::Result<std::option::Option<String>, iroha_config::derive::Error>
             {
-                Err(iroha_config::derive::Error::UnknownField(
-                    inner_field.as_ref().iter().map(ToString::to_string).collect()
-                ))
+                #unknown_field
             }
         };
     }
@@ -214,6 +433,7 @@ fn impl_get_doc_recursive(
         // XXX: Workaround
         //Decription of issue is here https://stackoverflow.com/a/65353489
         .fold(quote! {}, |acc, new| quote! { #acc #new });
+    let unknown_field = unknown_field_error(field_idents, quote! { field });
 
     quote! {
         fn get_doc_recursive<'a>(
@@ -223,9 +443,7 @@ fn impl_get_doc_recursive(
             let inner_field = inner_field.as_ref();
             let doc = match inner_field {
                 #variants
-                field => return Err(iroha_config::derive::Error::UnknownField(
-                    field.iter().map(ToString::to_string).collect()
-                )),
+                field => #unknown_field,
             };
             Ok(doc)
         }
@@ -309,6 +527,7 @@ fn impl_get_recursive(
     lvalue: &[proc_macro2::TokenStream],
 ) -> proc_macro2::TokenStream {
     if field_idents.is_empty() {
+        let unknown_field = unknown_field_error(field_idents, quote! { inner_field.as_ref() });
         return quote! {
             fn get_recursive<'a, T>(
                 &self,
@@ -317,9 +536,7 @@ fn impl_get_recursive(
             where
                 T: AsRef<[&'a str]> + Send + 'a,
             {
-                Err(iroha_config::derive::Error::UnknownField(
-                    inner_field.as_ref().iter().map(ToString::to_string).collect()
-                ))
+                #unknown_field
             }
         };
     }
@@ -348,6 +565,7 @@ fn impl_get_recursive(
         // XXX: Workaround
         //Decription of issue is here https://stackoverflow.com/a/65353489
         .fold(quote! {}, |acc, new| quote! { #acc #new });
+    let unknown_field = unknown_field_error(field_idents, quote! { field });
 
     quote! {
         fn get_recursive<'a, T>(
@@ -360,9 +578,7 @@ fn impl_get_recursive(
             let inner_field = inner_field.as_ref();
             let value = match inner_field {
                 #variants
-                field => return Err(iroha_config::derive::Error::UnknownField(
-                    field.iter().map(ToString::to_string).collect()
-                )),
+                field => #unknown_field,
             };
             Ok(value)
         }
@@ -418,6 +634,16 @@ fn impl_configurable(ast: &DeriveInput) -> TokenStream {
         })
         .collect::<Vec<_>>();
 
+    let default_value = field_attrs
+        .iter()
+        .map(|attrs| {
+            attrs
+                .iter()
+                .find_map(|attr| attr.parse_args::<DefaultValue>().ok())
+                .map(|default| default.value.value())
+        })
+        .collect::<Vec<_>>();
+
     let field_environment = field_idents
         .iter()
         .into_iter()
@@ -481,6 +707,16 @@ fn impl_configurable(ast: &DeriveInput) -> TokenStream {
         &field_ty,
         &field_environment,
     );
+    let load_defaults = impl_load_defaults(
+        &field_idents,
+        &inner,
+        &lvalue_write,
+        &as_str,
+        &field_ty,
+        &default_value,
+    );
+    let merge = impl_merge(&field_idents, &inner, &lvalue_write);
+    let load_layered = impl_load_layered();
     let get_recursive = impl_get_recursive(&field_idents, inner.clone(), &lvalue_read);
     let get_doc_recursive =
         impl_get_doc_recursive(&field_ty, &field_idents, inner.clone(), docs.clone());
@@ -496,6 +732,9 @@ fn impl_configurable(ast: &DeriveInput) -> TokenStream {
             #get_docs
             #get_inner_docs
             #load_environment
+            #load_defaults
+            #merge
+            #load_layered
         }
     };
     out.into()
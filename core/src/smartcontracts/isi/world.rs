@@ -32,7 +32,10 @@ pub mod isi {
                 }
 
                 Ok(PeerEvent::Added(peer_id).into())
-            })
+            })?;
+
+            wsv.metrics.peers.inc();
+            Ok(())
         }
     }
 
@@ -52,7 +55,10 @@ pub mod isi {
                 }
 
                 Ok(PeerEvent::Removed(peer_id).into())
-            })
+            })?;
+
+            wsv.metrics.peers.dec();
+            Ok(())
         }
     }
 
@@ -137,7 +143,10 @@ pub mod isi {
 
                 world.roles.insert(role_id.clone(), role);
                 Ok(RoleEvent::Created(role_id).into())
-            })
+            })?;
+
+            wsv.metrics.roles.inc();
+            Ok(())
         }
     }
 
@@ -181,7 +190,10 @@ pub mod isi {
                 }
 
                 Ok(RoleEvent::Deleted(role_id).into())
-            })
+            })?;
+
+            wsv.metrics.roles.dec();
+            Ok(())
         }
     }
 }
@@ -1,19 +1,29 @@
 //! This module provides the [`WorldStateView`] - in-memory representations of the current blockchain
 //! state.
 
-use std::{convert::Infallible, fmt::Debug, sync::Arc, time::Duration};
+use std::{
+    convert::Infallible,
+    fmt::Debug,
+    fs,
+    hash::Hash,
+    io,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use config::Configuration;
 use dashmap::{
     mapref::one::{Ref as DashMapRef, RefMut as DashMapRefMut},
-    DashSet,
+    DashMap, DashSet,
 };
 use eyre::Result;
 use getset::Getters;
 use iroha_crypto::HashOf;
-use iroha_data_model::{prelude::*, small::SmallVec};
+use iroha_data_model::{prelude::*, small::SmallVec, LengthLimits};
 use iroha_logger::prelude::*;
 use iroha_telemetry::metrics::Metrics;
+use parity_scale_codec::{Decode, Encode};
 use tokio::{sync::broadcast, task};
 
 use crate::{
@@ -70,6 +80,205 @@ impl World {
     }
 }
 
+/// Location of a [`VersionedTransaction`] within the blockchain: which
+/// block it was committed or rejected in, and where in that block's
+/// transaction list. Lets [`WorldStateView::transaction_value_by_hash`]
+/// resolve a hash in O(1) instead of scanning every block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TxLocation {
+    /// Height of the block the transaction is recorded in
+    block_height: u64,
+    /// Whether the transaction was rejected
+    rejected: bool,
+    /// Index into the block's `transactions` or `rejected_transactions`
+    index: usize,
+}
+
+/// Opaque resume point for [`WorldStateView::transactions_after_cursor`]:
+/// the height of the block to resume from, and the index within that
+/// block's combined rejected-then-accepted transaction list to resume at.
+/// Returned to the client alongside a page of results and fed back on the
+/// next call so a chain with millions of transactions never has to be
+/// materialized in memory all at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct TransactionQueryCursor {
+    block_height: u64,
+    index: u64,
+}
+
+/// Opaque resume point for [`WorldStateView::blocks_after_cursor`]: the
+/// height of the next block to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct BlockQueryCursor {
+    next_height: u64,
+}
+
+/// Per-block summary returned by `FindAllBlocks`: enough to list blocks
+/// without shipping every transaction inside them.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct BlockSummary {
+    height: u64,
+    hash: HashOf<VersionedCommittedBlock>,
+    timestamp_ms: u128,
+    accepted_transactions: u64,
+    rejected_transactions: u64,
+}
+
+/// On-disk format version for [`WsvSnapshot`]. Bump this whenever the
+/// layout below changes; [`WorldStateView::load`] refuses to restore from
+/// a snapshot carrying any other version and falls back to full replay.
+const WSV_SNAPSHOT_VERSION: u8 = 1;
+
+/// Page size for [`WorldStateView::transactions_after_cursor`] and
+/// [`WorldStateView::blocks_after_cursor`].
+const QUERY_BATCH_SIZE: usize = 1000;
+
+/// A point-in-time capture of [`WorldStateView`], letting `init` skip
+/// replaying every committed block from genesis on restart. Produced by
+/// [`WorldStateView::take_snapshot`]/[`WorldStateView::write_snapshot`],
+/// consumed by [`WorldStateView::from_snapshot`].
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct WsvSnapshot {
+    version: u8,
+    domains: Vec<Domain>,
+    roles: Vec<Role>,
+    trusted_peers_ids: Vec<<Peer as Identifiable>::Id>,
+    triggers: TriggerSet,
+    transactions: Vec<HashOf<VersionedTransaction>>,
+    block_height: u64,
+    latest_block_hash: HashOf<VersionedCommittedBlock>,
+}
+
+/// A read-only, point-in-time view over [`WorldStateView`]'s `world`,
+/// obtained via [`WorldStateView::state_read_only`] so a query can run
+/// against a snapshot that can't change underneath it, without contending
+/// with `apply`'s writer for the `domains`/`roles`/`trusted_peers_ids`
+/// [`DashMap`]s/[`DashSet`] it holds locks over while mutating them.
+///
+/// This is a full copy taken at the moment it's requested, not a true
+/// persistent/MVCC structure with structurally-shared subtrees and O(1)
+/// snapshotting — that would mean swapping `World`'s `DashMap`/`DashSet`
+/// fields for a persistent-map crate and reworking every ISI
+/// implementation that touches them (`smartcontracts::isi::world` and
+/// friends), which is out of scope here. Once taken, the copy is
+/// independent of `world`, so a query running against it holds no lock the
+/// writer needs. Taking the copy is not synchronised with `apply`, and the
+/// maps are cloned one after another: a snapshot requested while a block is
+/// being applied can hold part of that block's changes.
+#[derive(Debug, Clone)]
+pub struct StateReadOnly {
+    domains: DomainsMap,
+    roles: crate::RolesMap,
+    trusted_peers_ids: PeersIds,
+    triggers: TriggerSet,
+}
+
+impl StateReadOnly {
+    /// Registered domains, as of when this snapshot was taken.
+    #[inline]
+    pub fn domains(&self) -> &DomainsMap {
+        &self.domains
+    }
+
+    /// Roles, as of when this snapshot was taken.
+    #[inline]
+    pub fn roles(&self) -> &crate::RolesMap {
+        &self.roles
+    }
+
+    /// Trusted peer ids, as of when this snapshot was taken.
+    #[inline]
+    pub fn trusted_peers_ids(&self) -> &PeersIds {
+        &self.trusted_peers_ids
+    }
+
+    /// Triggers, as of when this snapshot was taken.
+    #[inline]
+    pub fn triggers(&self) -> &TriggerSet {
+        &self.triggers
+    }
+}
+
+/// Pluggable storage for blocks pruned out of the resident [`Chain`] by
+/// [`WorldStateView`]'s `keep_recent_blocks` cap, following the "ancient
+/// block" separation used in the OpenEthereum client: recent history stays
+/// in memory for fast access, everything older lives here instead, and
+/// queries transparently fall back to it when a block isn't resident
+/// anymore.
+pub trait BlockStorage: Debug + Send + Sync {
+    /// Persist `block`, so it can be retrieved after it's pruned from the
+    /// resident [`Chain`].
+    ///
+    /// # Errors
+    /// Fails if the block can't be written to the backing store.
+    fn store_block(&self, block: &VersionedCommittedBlock) -> io::Result<()>;
+
+    /// Look up a block by height.
+    fn get_block(&self, height: u64) -> Option<VersionedCommittedBlock>;
+
+    /// Look up a block by hash.
+    fn get_block_by_hash(
+        &self,
+        hash: HashOf<VersionedCommittedBlock>,
+    ) -> Option<VersionedCommittedBlock>;
+
+    /// Blocks with height in `[from, to)`, in ascending height order.
+    fn range(&self, from: u64, to: u64) -> Vec<VersionedCommittedBlock>;
+}
+
+/// Emitted once per successfully committed block, right after `blocks.push`
+/// and the metrics update, so triggers and external subscribers can react
+/// to a commit without re-deriving its shape from `blocks_after_hash`.
+/// `events` is the full, ordered batch of time/data/trigger events produced
+/// while applying the block (see [`ApplyJournal::buffered_events`]), handed
+/// over atomically here rather than left for subscribers to reassemble from
+/// separate broadcasts.
+#[derive(Debug, Clone)]
+pub struct BlockEvent {
+    /// Height of the committed block.
+    pub height: u64,
+    /// Hash of the committed block.
+    pub hash: HashOf<VersionedCommittedBlock>,
+    /// Number of transactions accepted into the block.
+    pub accepted_transactions: u64,
+    /// Number of transactions rejected from the block.
+    pub rejected_transactions: u64,
+    /// Block timestamp, in milliseconds since the Unix epoch.
+    pub timestamp_ms: u128,
+    /// Every event produced while applying this block.
+    pub events: Vec<Event>,
+}
+
+/// A rollback point for a single `apply`, modeled on OpenEthereum's
+/// `DBTransaction` commit model: a snapshot of everything `modify_world`
+/// (and, through it, `modify_domain`/`modify_account`/`modify_asset`) can
+/// touch, taken before a block's transactions and triggers run, plus every
+/// event produced while it's active. `produce_event` buffers into
+/// `buffered_events` instead of broadcasting while a journal is open, so a
+/// rollback can discard them unsent.
+///
+/// Scoped to a whole `apply` rather than to each individual `modify_*`
+/// call, since every one of them ultimately mutates `domains`/`roles`/
+/// `trusted_peers_ids` and nothing else reachable from `World`.
+///
+/// Known limitation: trigger registrations made mid-block aren't covered,
+/// since `TriggerSet` doesn't expose a snapshot/restore primitive here;
+/// only the maps above and the event buffer are actually transactional.
+#[derive(Debug)]
+struct ApplyJournal {
+    domains: DomainsMap,
+    roles: crate::RolesMap,
+    trusted_peers_ids: PeersIds,
+    buffered_events: Vec<Event>,
+    /// Transaction hashes seen while this journal is open, held back from
+    /// `WorldStateView::transactions` until `commit_journal` instead of
+    /// being snapshotted/restored like the maps above - `transactions`
+    /// only ever grows across the whole chain's history, so cloning it per
+    /// `apply` the way `domains`/`roles` are cloned would be far more
+    /// expensive than just deferring the insert.
+    pending_transactions: Vec<HashOf<VersionedTransaction>>,
+}
+
 /// Current state of the blockchain aligned with `Iroha` module.
 #[derive(Debug)]
 pub struct WorldStateView {
@@ -81,6 +290,19 @@ pub struct WorldStateView {
     blocks: Arc<Chain>,
     /// Hashes of transactions
     pub transactions: DashSet<HashOf<VersionedTransaction>>,
+    /// Index from transaction hash to its location in the blockchain, kept
+    /// in sync with `blocks` so lookups by hash don't scan every block.
+    transaction_locations: DashMap<HashOf<VersionedTransaction>, TxLocation>,
+    /// Index from account to the hashes of transactions it submitted, so
+    /// per-account transaction lookups don't scan every block either.
+    transactions_by_account: DashMap<AccountId, Vec<HashOf<VersionedTransaction>>>,
+    /// Rollback point for the `apply` currently in progress, if any. See
+    /// [`ApplyJournal`].
+    journal: Mutex<Option<ApplyJournal>>,
+    /// Backing store for blocks pruned out of `blocks` by
+    /// `config.keep_recent_blocks`. `None` keeps every block resident
+    /// forever, same as before pruning support existed.
+    block_storage: Mutex<Option<Arc<dyn BlockStorage>>>,
     /// Metrics for prometheus endpoint.
     pub metrics: Arc<Metrics>,
     /// Notifies subscribers when new block is applied
@@ -101,9 +323,19 @@ impl Clone for WorldStateView {
     fn clone(&self) -> Self {
         Self {
             world: Clone::clone(&self.world),
-            config: self.config,
+            config: self.config.clone(),
             blocks: Arc::clone(&self.blocks),
             transactions: self.transactions.clone(),
+            transaction_locations: self.transaction_locations.clone(),
+            transactions_by_account: self.transactions_by_account.clone(),
+            // A clone never has an `apply` of its own in progress.
+            journal: Mutex::new(None),
+            block_storage: Mutex::new(
+                self.block_storage
+                    .lock()
+                    .expect("block storage mutex poisoned")
+                    .clone(),
+            ),
             metrics: Arc::clone(&self.metrics),
             new_block_notifier: Arc::clone(&self.new_block_notifier),
             events_sender: self.events_sender.clone(),
@@ -144,7 +376,20 @@ impl WorldStateView {
         tokens
     }
 
-    fn process_executable(&self, executable: &Executable, authority: &AccountId) -> Result<()> {
+    /// Run `executable` as `authority`. `trigger_id` is `Some` when
+    /// `executable` belongs to a trigger currently firing, in which case a
+    /// WASM module sees it as the `id` argument of its `main` entrypoint
+    /// (ABI v2: `fn main(id: TriggerId, owner: AccountId, event: Event)`),
+    /// letting trigger code self-reference to unregister itself after a
+    /// one-shot run, re-schedule its next execution, or touch its own
+    /// metadata. `None` for plain transaction executables, which have no
+    /// trigger to report.
+    fn process_executable(
+        &self,
+        executable: &Executable,
+        authority: &AccountId,
+        trigger_id: Option<&TriggerId>,
+    ) -> Result<()> {
         match executable {
             Executable::Instructions(instructions) => {
                 instructions.iter().cloned().try_for_each(|instruction| {
@@ -155,7 +400,7 @@ impl WorldStateView {
             Executable::Wasm(bytes) => {
                 let mut wasm_runtime =
                     wasm::Runtime::from_configuration(self.config.wasm_runtime_config)?;
-                wasm_runtime.execute(self, authority, bytes)?;
+                wasm_runtime.execute(self, authority, trigger_id, bytes)?;
             }
         }
         Ok(())
@@ -167,13 +412,20 @@ impl WorldStateView {
     /// Order of execution:
     /// 1) Transactions
     /// 2) Triggers
+    /// 3) Block-commit triggers, once the block is actually resident in
+    ///    `blocks` (see [`BlockEvent`])
+    ///
+    /// Steps 1 and 2 run under an [`ApplyJournal`] opened by
+    /// [`Self::begin_journal`]: if transaction or trigger execution fails,
+    /// everything the journal covers is restored with
+    /// [`Self::rollback_journal`] and the events produced along the way are
+    /// discarded, instead of committing a half-mutated block.
     ///
     /// # Errors
     ///
-    /// - (RARE) if applying transaction after validation fails.  This
-    /// scenario is rare, because the `tx` validation implies applying
-    /// instructions directly to a clone of the wsv.  If this happens,
-    /// you likely have data corruption.
+    /// - If applying a transaction fails. This used to leave the `wsv`
+    /// half-mutated and was flagged as a rare data-corruption risk; it's now
+    /// rolled back like any other failure here.
     /// - If trigger execution fails
     /// - If timestamp conversion to `u64` fails
     #[iroha_futures::telemetry_future]
@@ -181,35 +433,154 @@ impl WorldStateView {
     #[allow(clippy::expect_used)]
     pub async fn apply(&self, block: VersionedCommittedBlock) -> Result<()> {
         let time_event = self.create_time_event(block.as_v1())?;
+
+        self.begin_journal();
+        if let Err(error) = self
+            .apply_transactions_and_triggers(block.as_v1(), time_event)
+            .await
+        {
+            self.rollback_journal();
+            return Err(error);
+        }
+        let events = self.commit_journal();
+
+        let block_height = block.as_v1().header.height;
+        // Push first, index second: `index_transactions` must never make a
+        // hash resolvable through `transaction_locations` before the block
+        // it points into is visible via `self.blocks`, or a concurrent
+        // `transaction_value_by_hash` could look up a location and fail to
+        // find its block.
+        self.blocks.push(block.clone());
+        self.index_transactions(block.as_v1(), block_height);
+        self.block_commit_metrics_update_callback();
+        self.prune_expired_permission_tokens();
+        self.prune_to_storage(block_height);
+
+        let block_event = BlockEvent {
+            height: block_height,
+            hash: block.hash(),
+            accepted_transactions: block.as_v1().transactions.len() as u64,
+            rejected_transactions: block.as_v1().rejected_transactions.len() as u64,
+            timestamp_ms: block.as_v1().header.timestamp,
+            events,
+        };
+        self.world.triggers.handle_block_event(&block_event);
+        self.produce_event(Event::Block(block_event));
+
+        self.new_block_notifier.send_replace(());
+
+        let snapshot_every = self.config.snapshot_create_every;
+        if snapshot_every > 0 && block_height % snapshot_every == 0 {
+            if let Err(error) = self.write_snapshot(&self.config.snapshot_path) {
+                error!(%error, "Failed to write WSV snapshot");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the transaction and trigger-execution steps of [`Self::apply`],
+    /// returning the first error either of them produces instead of just
+    /// logging trigger failures and carrying on.
+    async fn apply_transactions_and_triggers(
+        &self,
+        block: &CommittedBlock,
+        time_event: TimeEvent,
+    ) -> Result<()> {
         self.produce_event(Event::Time(time_event));
 
-        self.execute_transactions(block.as_v1()).await?;
+        self.execute_transactions(block).await?;
 
         self.world.triggers.handle_time_event(&time_event);
 
-        let res = self
-            .world
+        self.world
             .triggers
             .inspect_matched(|action| -> Result<()> {
-                self.process_executable(action.executable(), action.technical_account())
+                self.process_executable(
+                    action.executable(),
+                    action.technical_account(),
+                    Some(action.id()),
+                )
             })
-            .await;
+            .await
+            .map_err(|errors| eyre::eyre!("Trigger execution failed: {errors:?}"))
+    }
 
-        if let Err(errors) = res {
-            warn!(
-                ?errors,
-                "The following errors have occurred during trigger execution"
-            );
+    /// Open a rollback point for the `apply` currently starting, snapshotting
+    /// everything [`ApplyJournal`] covers. Must be paired with exactly one of
+    /// [`Self::commit_journal`]/[`Self::rollback_journal`].
+    fn begin_journal(&self) {
+        *self.journal.lock().expect("journal mutex poisoned") = Some(ApplyJournal {
+            domains: self.world.domains.clone(),
+            roles: self.world.roles.clone(),
+            trusted_peers_ids: self.world.trusted_peers_ids.clone(),
+            buffered_events: Vec::new(),
+            pending_transactions: Vec::new(),
+        });
+    }
+
+    /// Close the current journal successfully: inserting every transaction
+    /// hash it held back into [`Self::transactions`], flushing every event
+    /// it buffered to subscribers, and returning the same batch so the
+    /// caller can also hand it to the block-commit event.
+    fn commit_journal(&self) -> Vec<Event> {
+        let journal = self.journal.lock().expect("journal mutex poisoned").take();
+        let Some(journal) = journal else {
+            return Vec::new();
+        };
+
+        for hash in journal.pending_transactions {
+            self.transactions.insert(hash);
         }
 
-        self.blocks.push(block);
-        self.block_commit_metrics_update_callback();
-        self.new_block_notifier.send_replace(());
+        for event in journal.buffered_events.clone() {
+            let _result = self.events_sender.send(event);
+        }
 
-        // TODO: On block commit triggers
-        // TODO: Pass self.events to the next block
+        journal.buffered_events
+    }
 
-        Ok(())
+    /// Close the current journal by restoring `domains`, `roles` and
+    /// `trusted_peers_ids` to how they looked when it was opened, discarding
+    /// every event it buffered and every transaction hash it held back
+    /// instead of committing them.
+    fn rollback_journal(&self) {
+        let journal = self.journal.lock().expect("journal mutex poisoned").take();
+        let Some(journal) = journal else {
+            return;
+        };
+
+        Self::restore_map(&self.world.domains, journal.domains);
+        Self::restore_map(&self.world.roles, journal.roles);
+        Self::restore_set(&self.world.trusted_peers_ids, journal.trusted_peers_ids);
+        // `journal.buffered_events` and `journal.pending_transactions` are
+        // dropped here, unsent and uninserted.
+    }
+
+    /// Replace the contents of `target` with those of `snapshot`, in place,
+    /// so existing `&DashMap` references (e.g. [`World::domains`]) keep
+    /// pointing at the same map.
+    fn restore_map<K, V>(target: &DashMap<K, V>, snapshot: DashMap<K, V>)
+    where
+        K: Eq + Hash + Clone,
+        V: Clone,
+    {
+        target.clear();
+        for entry in snapshot.iter() {
+            target.insert(entry.key().clone(), entry.value().clone());
+        }
+    }
+
+    /// Replace the contents of `target` with those of `snapshot`, in place,
+    /// analogous to [`Self::restore_map`] but for a [`DashSet`].
+    fn restore_set<K>(target: &DashSet<K>, snapshot: DashSet<K>)
+    where
+        K: Eq + Hash + Clone,
+    {
+        target.clear();
+        for entry in snapshot.iter() {
+            target.insert(entry.clone());
+        }
     }
 
     /// Create time event using previous and current blocks
@@ -244,17 +615,71 @@ impl WorldStateView {
     async fn execute_transactions(&self, block: &CommittedBlock) -> Result<()> {
         // TODO: Should this block panic instead?
         for tx in &block.transactions {
-            self.process_executable(&tx.as_v1().payload.instructions, &tx.payload().account_id)?;
-            self.transactions.insert(tx.hash());
+            self.process_executable(
+                &tx.as_v1().payload.instructions,
+                &tx.payload().account_id,
+                None,
+            )?;
+            self.note_transaction_hash(tx.hash());
             task::yield_now().await;
         }
         for tx in &block.rejected_transactions {
-            self.transactions.insert(tx.hash());
+            self.note_transaction_hash(tx.hash());
         }
 
         Ok(())
     }
 
+    /// Records `hash` as seen. While a journal is open this is held back in
+    /// [`ApplyJournal::pending_transactions`] until [`Self::commit_journal`]
+    /// instead of going straight into [`Self::transactions`], so a
+    /// [`Self::rollback_journal`] doesn't leave a rolled-back block's
+    /// transactions falsely marked as already seen.
+    fn note_transaction_hash(&self, hash: HashOf<VersionedTransaction>) {
+        let mut journal = self.journal.lock().expect("journal mutex poisoned");
+        if let Some(journal) = journal.as_mut() {
+            journal.pending_transactions.push(hash);
+        } else {
+            self.transactions.insert(hash);
+        }
+    }
+
+    /// Populate the transaction-address index for `block`, whose height is
+    /// `block_height`. Must only be called once `block` is already visible
+    /// through `self.blocks` — see the call site in [`Self::apply`].
+    fn index_transactions(&self, block: &CommittedBlock, block_height: u64) {
+        for (index, tx) in block.transactions.iter().enumerate() {
+            let hash = tx.hash();
+            self.transaction_locations.insert(
+                hash,
+                TxLocation {
+                    block_height,
+                    rejected: false,
+                    index,
+                },
+            );
+            self.transactions_by_account
+                .entry(tx.payload().account_id.clone())
+                .or_default()
+                .push(hash);
+        }
+        for (index, tx) in block.rejected_transactions.iter().enumerate() {
+            let hash = tx.hash();
+            self.transaction_locations.insert(
+                hash,
+                TxLocation {
+                    block_height,
+                    rejected: true,
+                    index,
+                },
+            );
+            self.transactions_by_account
+                .entry(tx.payload().account_id.clone())
+                .or_default()
+                .push(hash);
+        }
+    }
+
     /// Get `Asset` by its id
     ///
     /// # Errors
@@ -270,9 +695,17 @@ impl WorldStateView {
         })?
     }
 
-    /// Send [`Event`]s to known subscribers.
+    /// Send [`Event`]s to known subscribers, unless an [`ApplyJournal`] is
+    /// currently open, in which case the event is buffered there instead so
+    /// a rollback can discard it unsent.
     fn produce_event(&self, event: impl Into<Event>) {
-        let _result = self.events_sender.send(event.into());
+        let event = event.into();
+        let mut journal = self.journal.lock().expect("journal mutex poisoned");
+        if let Some(journal) = journal.as_mut() {
+            journal.buffered_events.push(event);
+        } else {
+            let _result = self.events_sender.send(event);
+        }
     }
 
     /// Tries to get asset or inserts new with `default_asset_value`.
@@ -334,6 +767,29 @@ impl WorldStateView {
         self.metrics.block_height.inc();
     }
 
+    /// Drop permission tokens whose `expires_at` parameter names a block
+    /// height at or before the current one from every account, so that
+    /// lapsed delegations (see `iroha_permissions_validators`' expiring
+    /// `CanModify*` tokens) don't linger in state forever.
+    fn prune_expired_permission_tokens(&self) {
+        static EXPIRES_AT: once_cell::sync::Lazy<Name> =
+            once_cell::sync::Lazy::new(|| "expires_at".parse().expect("Tested. Works."));
+
+        let height = self.height();
+        for mut domain in self.world.domains.iter_mut() {
+            for account in domain.accounts_mut() {
+                account.permissions_mut().retain(|token| {
+                    !token
+                        .params()
+                        .find(|(name, _)| **name == *EXPIRES_AT)
+                        .is_some_and(|(_, value)| {
+                            matches!(value, Value::U128(expires_at) if *expires_at != 0 && u128::from(height) >= *expires_at)
+                        })
+                });
+            }
+        }
+    }
+
     // TODO: There could be just this one method `blocks` instead of
     // `blocks_from_height` and `blocks_after_height`. Also, this
     // method would return references instead of cloning blockchain
@@ -348,15 +804,115 @@ impl WorldStateView {
         self.blocks.iter()
     }
 
-    /// Returns iterator over blockchain blocks after the block with the given `hash`
+    /// Returns iterator over blockchain blocks after the block with the given `hash`.
+    ///
+    /// Transparently merges in the on-disk ancient range from `block_storage`
+    /// when `hash` names a block that's already been pruned out of `blocks`.
     pub fn blocks_after_hash(
         &self,
         hash: HashOf<VersionedCommittedBlock>,
     ) -> impl Iterator<Item = VersionedCommittedBlock> + '_ {
+        let ancient_hit = self
+            .blocks
+            .iter()
+            .all(|block_entry| block_entry.value().hash() != hash)
+            .then(|| self.ancient_block_by_hash(hash))
+            .flatten();
+
+        let (ancient, resident): (Vec<_>, Box<dyn Iterator<Item = VersionedCommittedBlock> + '_>) =
+            match ancient_hit {
+                Some(found) => (
+                    self.fetch_ancient_range(
+                        found.as_v1().header.height + 1,
+                        self.resident_start_height(),
+                    ),
+                    Box::new(self.blocks.iter().map(|block_entry| block_entry.value().clone())),
+                ),
+                None => (
+                    Vec::new(),
+                    Box::new(
+                        self.blocks
+                            .iter()
+                            .skip_while(move |block_entry| {
+                                block_entry.value().header().previous_block_hash != hash
+                            })
+                            .map(|block_entry| block_entry.value().clone()),
+                    ),
+                ),
+            };
+
+        ancient.into_iter().chain(resident)
+    }
+
+    /// Height of the oldest block still resident in `blocks`, or `1` if
+    /// `blocks` is empty.
+    fn resident_start_height(&self) -> u64 {
         self.blocks
             .iter()
-            .skip_while(move |block_entry| block_entry.value().header().previous_block_hash != hash)
-            .map(|block_entry| block_entry.value().clone())
+            .next()
+            .map_or(1, |block_entry| block_entry.value().as_v1().header.height)
+    }
+
+    /// Blocks with height in `[from, to)` that are no longer resident,
+    /// fetched from `block_storage` if one is configured.
+    fn fetch_ancient_range(&self, from: u64, to: u64) -> Vec<VersionedCommittedBlock> {
+        if from >= to {
+            return Vec::new();
+        }
+
+        self.block_storage
+            .lock()
+            .expect("block storage mutex poisoned")
+            .as_ref()
+            .map_or_else(Vec::new, |storage| storage.range(from, to))
+    }
+
+    /// Look up `hash` in `block_storage`, if one is configured.
+    fn ancient_block_by_hash(
+        &self,
+        hash: HashOf<VersionedCommittedBlock>,
+    ) -> Option<VersionedCommittedBlock> {
+        self.block_storage
+            .lock()
+            .expect("block storage mutex poisoned")
+            .as_ref()
+            .and_then(|storage| storage.get_block_by_hash(hash))
+    }
+
+    /// Make `storage` the backing store for blocks pruned by
+    /// `config.keep_recent_blocks`.
+    pub fn set_block_storage(&self, storage: Arc<dyn BlockStorage>) {
+        *self
+            .block_storage
+            .lock()
+            .expect("block storage mutex poisoned") = Some(storage);
+    }
+
+    /// Offload blocks older than `config.keep_recent_blocks` out of the
+    /// resident [`Chain`] and into `block_storage`, if one is configured, so
+    /// steady-state memory stays bounded regardless of chain height.
+    /// Disabled (`0`, the default) keeps every block resident, same as
+    /// before pruning support existed.
+    fn prune_to_storage(&self, block_height: u64) {
+        let keep_recent = self.config.keep_recent_blocks;
+        if keep_recent == 0 || block_height <= keep_recent {
+            return;
+        }
+
+        let storage = self
+            .block_storage
+            .lock()
+            .expect("block storage mutex poisoned")
+            .clone();
+        let Some(storage) = storage else {
+            return;
+        };
+
+        for block in self.blocks.prune_before(block_height - keep_recent + 1) {
+            if let Err(error) = storage.store_block(&block) {
+                error!(%error, "Failed to offload block to on-disk storage");
+            }
+        }
     }
 
     /// Get `World` and pass it to closure to modify it
@@ -392,15 +948,50 @@ impl WorldStateView {
         &self.world.trusted_peers_ids
     }
 
-    /// Returns iterator over blockchain blocks starting with the block of the given `height`
+    /// Returns iterator over blockchain blocks starting with the block of the given `height`.
+    ///
+    /// Transparently merges in the on-disk ancient range from
+    /// `block_storage` for any part of `[height, ..]` that's been pruned
+    /// out of `blocks`.
     pub fn blocks_from_height(
         &self,
         height: usize,
     ) -> impl Iterator<Item = VersionedCommittedBlock> + '_ {
-        self.blocks
-            .iter()
-            .skip(height.saturating_sub(1))
-            .map(|block_entry| block_entry.value().clone())
+        let height = height as u64;
+        let resident_start = self.resident_start_height();
+        let ancient = self.fetch_ancient_range(height, resident_start);
+        let resident_skip = height.saturating_sub(resident_start) as usize;
+
+        ancient.into_iter().chain(
+            self.blocks
+                .iter()
+                .skip(resident_skip)
+                .map(|block_entry| block_entry.value().clone()),
+        )
+    }
+
+    /// The effective [`LengthLimits`] for identifiers registered within
+    /// `domain_id`'s own subtree (e.g. an account or asset definition
+    /// name): `domain_id`'s own override if it has set one (clamped to
+    /// never exceed the global config's ceiling), falling back to
+    /// [`config::Configuration::ident_length_limits`] if the domain has no
+    /// override or doesn't exist. Per-entity registration ISI should
+    /// resolve through this instead of consulting `self.config` directly,
+    /// the same way [`Self::domain`] is the one place that resolves a
+    /// [`DomainId`] into a [`Domain`].
+    pub fn effective_ident_length_limits(&self, domain_id: &DomainId) -> LengthLimits {
+        let global = self.config.ident_length_limits;
+        let Ok(domain) = self.domain(domain_id) else {
+            return global;
+        };
+        let Some(over) = domain.ident_length_limits_override() else {
+            return global;
+        };
+
+        LengthLimits::new(
+            over.min().max(global.min()),
+            over.max().min(global.max()),
+        )
     }
 
     /// Get `Domain` without an ability to modify it.
@@ -496,6 +1087,10 @@ impl WorldStateView {
             world,
             config,
             transactions: DashSet::new(),
+            transaction_locations: DashMap::new(),
+            transactions_by_account: DashMap::new(),
+            journal: Mutex::new(None),
+            block_storage: Mutex::new(None),
             blocks: Arc::new(Chain::new()),
             metrics: Arc::new(Metrics::default()),
             new_block_notifier: Arc::new(new_block_notifier),
@@ -537,6 +1132,148 @@ impl WorldStateView {
         }
     }
 
+    /// Initializes a fresh [`WorldStateView`] from `all_blocks` (the full
+    /// contents of block storage), skipping full replay when a usable
+    /// snapshot is found at `config.snapshot_path`.
+    ///
+    /// A snapshot is usable only if its `latest_block_hash` matches the
+    /// hash of the block `all_blocks` actually has at that height; any
+    /// mismatch (corruption, a snapshot taken on a now-abandoned fork)
+    /// falls back to full replay from genesis, same as if no snapshot
+    /// existed at all.
+    ///
+    /// If `block_storage` is given, it's wired up as the backing store for
+    /// `config.keep_recent_blocks` pruning and caught up in one pass right
+    /// away, so a restart doesn't keep the whole chain resident until the
+    /// next block is applied.
+    pub async fn load(
+        config: Configuration,
+        events_sender: EventsSender,
+        mut all_blocks: Vec<VersionedCommittedBlock>,
+        block_storage: Option<Arc<dyn BlockStorage>>,
+    ) -> Self {
+        let wsv = if let Some(snapshot) = Self::read_snapshot(&config.snapshot_path) {
+            let height = snapshot.block_height as usize;
+            let snapshot_is_valid = height <= all_blocks.len()
+                && height > 0
+                && all_blocks[height - 1].hash() == snapshot.latest_block_hash;
+
+            if snapshot_is_valid {
+                let remaining_blocks = all_blocks.split_off(height);
+                Self::from_snapshot(snapshot, remaining_blocks, config, events_sender).await
+            } else {
+                warn!("WSV snapshot doesn't match block storage, falling back to full replay");
+                let wsv = Self::from_configuration(config, World::new(), events_sender);
+                wsv.init(all_blocks).await;
+                wsv
+            }
+        } else {
+            let wsv = Self::from_configuration(config, World::new(), events_sender);
+            wsv.init(all_blocks).await;
+            wsv
+        };
+
+        if let Some(storage) = block_storage {
+            wsv.set_block_storage(storage);
+            wsv.prune_to_storage(wsv.height());
+        }
+
+        wsv
+    }
+
+    /// Serialize the current state into a [`WsvSnapshot`]: `world`'s
+    /// domains, roles, trusted peers and triggers, the set of known
+    /// transaction hashes, and the current block height/latest hash.
+    pub fn take_snapshot(&self) -> WsvSnapshot {
+        WsvSnapshot {
+            version: WSV_SNAPSHOT_VERSION,
+            domains: self
+                .world
+                .domains
+                .iter()
+                .map(|entry| entry.value().clone())
+                .collect(),
+            roles: self
+                .world
+                .roles
+                .iter()
+                .map(|entry| entry.value().clone())
+                .collect(),
+            trusted_peers_ids: self
+                .world
+                .trusted_peers_ids
+                .iter()
+                .map(|id| id.clone())
+                .collect(),
+            triggers: self.world.triggers.clone(),
+            transactions: self.transactions.iter().map(|hash| *hash).collect(),
+            block_height: self.height(),
+            latest_block_hash: self.latest_block_hash(),
+        }
+    }
+
+    /// Write [`Self::take_snapshot`]'s result to `path`, atomically: encode
+    /// to a temp file next to `path`, then rename it into place, so a
+    /// concurrent reader never observes a partially-written snapshot.
+    ///
+    /// # Errors
+    /// Fails if the temp file can't be written, or if renaming it over
+    /// `path` fails.
+    pub fn write_snapshot(&self, path: &Path) -> io::Result<()> {
+        let encoded = self.take_snapshot().encode();
+        let temp_path = path.with_extension("tmp");
+        fs::write(&temp_path, encoded)?;
+        fs::rename(&temp_path, path)
+    }
+
+    /// Read and decode a [`WsvSnapshot`] from `path`, if one exists and is
+    /// of a recognised [`WSV_SNAPSHOT_VERSION`].
+    fn read_snapshot(path: &Path) -> Option<WsvSnapshot> {
+        let bytes = fs::read(path).ok()?;
+        let snapshot = WsvSnapshot::decode(&mut bytes.as_slice()).ok()?;
+        (snapshot.version == WSV_SNAPSHOT_VERSION).then_some(snapshot)
+    }
+
+    /// Restore a [`WorldStateView`] directly from `snapshot`, then `apply`
+    /// every block in `remaining_blocks` (blocks newer than the
+    /// snapshot's height) on top of it.
+    #[allow(clippy::expect_used)]
+    pub async fn from_snapshot(
+        snapshot: WsvSnapshot,
+        remaining_blocks: Vec<VersionedCommittedBlock>,
+        config: Configuration,
+        events_sender: EventsSender,
+    ) -> Self {
+        let domains = snapshot
+            .domains
+            .into_iter()
+            .map(|domain| (domain.id().clone(), domain))
+            .collect();
+        let roles = snapshot
+            .roles
+            .into_iter()
+            .map(|role| (role.id().clone(), role))
+            .collect();
+        let trusted_peers_ids = snapshot.trusted_peers_ids.into_iter().collect();
+
+        let world = World {
+            trusted_peers_ids,
+            domains,
+            roles,
+            triggers: snapshot.triggers,
+            ..World::new()
+        };
+
+        let wsv = Self::from_configuration(config, world, events_sender);
+        for hash in snapshot.transactions {
+            wsv.transactions.insert(hash);
+        }
+        wsv.metrics.block_height.set(snapshot.block_height);
+
+        wsv.init(remaining_blocks).await;
+        wsv
+    }
+
     /// Hash of latest block
     pub fn latest_block_hash(&self) -> HashOf<VersionedCommittedBlock> {
         self.blocks
@@ -655,7 +1392,7 @@ impl WorldStateView {
     /// Get all transactions
     pub fn transaction_values(&self) -> Vec<TransactionValue> {
         let mut txs = self
-            .blocks()
+            .blocks_from_height(1)
             .flat_map(|block| {
                 let block = block.as_v1();
                 block
@@ -680,30 +1417,123 @@ impl WorldStateView {
         txs
     }
 
-    /// Find a [`VersionedTransaction`] by hash.
-    pub fn transaction_value_by_hash(
+    /// Transactions after `cursor` (or from the start of the chain, if
+    /// `None`), at most [`QUERY_BATCH_SIZE`] of them, paired with a cursor
+    /// to resume from if more remain. Unlike [`Self::transaction_values`],
+    /// this clones resident blocks one at a time until its page is full
+    /// instead of collecting and sorting the whole history; blocks already
+    /// offloaded to `block_storage` from the cursor's height onward are
+    /// fetched in one batch.
+    pub fn transactions_after_cursor(
         &self,
-        hash: &HashOf<VersionedTransaction>,
-    ) -> Option<TransactionValue> {
-        self.blocks.iter().find_map(|b| {
-            b.as_v1()
+        cursor: Option<TransactionQueryCursor>,
+    ) -> (Vec<TransactionValue>, Option<TransactionQueryCursor>) {
+        let (start_height, start_index) =
+            cursor.map_or((1, 0), |cursor| (cursor.block_height, cursor.index as usize));
+
+        let mut page = Vec::new();
+        let mut next_cursor = None;
+
+        'blocks: for block in self.blocks_from_height(start_height as usize) {
+            let block = block.as_v1();
+            let block_height = block.header.height;
+            let skip = if block_height == start_height {
+                start_index
+            } else {
+                0
+            };
+
+            let values = block
                 .rejected_transactions
                 .iter()
-                .find(|e| e.hash() == *hash)
                 .cloned()
                 .map(Box::new)
                 .map(TransactionValue::RejectedTransaction)
-                .or_else(|| {
-                    b.as_v1()
+                .chain(
+                    block
                         .transactions
                         .iter()
-                        .find(|e| e.hash() == *hash)
                         .cloned()
                         .map(VersionedTransaction::from)
                         .map(Box::new)
-                        .map(TransactionValue::Transaction)
-                })
-        })
+                        .map(TransactionValue::Transaction),
+                )
+                .collect::<Vec<_>>();
+
+            for (index, value) in values.into_iter().enumerate().skip(skip) {
+                if page.len() == QUERY_BATCH_SIZE {
+                    next_cursor = Some(TransactionQueryCursor {
+                        block_height,
+                        index: index as u64,
+                    });
+                    break 'blocks;
+                }
+                page.push(value);
+            }
+        }
+
+        (page, next_cursor)
+    }
+
+    /// Block headers after `cursor` (or from the start of the chain, if
+    /// `None`), at most [`QUERY_BATCH_SIZE`] of them, paired with a cursor
+    /// to resume from if more remain.
+    pub fn blocks_after_cursor(
+        &self,
+        cursor: Option<BlockQueryCursor>,
+    ) -> (Vec<BlockSummary>, Option<BlockQueryCursor>) {
+        let start_height = cursor.map_or(1, |cursor| cursor.next_height);
+
+        let mut page = Vec::new();
+        let mut next_cursor = None;
+
+        for block in self.blocks_from_height(start_height as usize) {
+            if page.len() == QUERY_BATCH_SIZE {
+                next_cursor = Some(BlockQueryCursor {
+                    next_height: block.as_v1().header.height,
+                });
+                break;
+            }
+
+            let hash = block.hash();
+            let block = block.as_v1();
+            page.push(BlockSummary {
+                height: block.header.height,
+                hash,
+                timestamp_ms: block.header.timestamp,
+                accepted_transactions: block.transactions.len() as u64,
+                rejected_transactions: block.rejected_transactions.len() as u64,
+            });
+        }
+
+        (page, next_cursor)
+    }
+
+    /// Find a [`VersionedTransaction`] by hash.
+    pub fn transaction_value_by_hash(
+        &self,
+        hash: &HashOf<VersionedTransaction>,
+    ) -> Option<TransactionValue> {
+        let location = *self.transaction_locations.get(hash)?;
+        let block = self.blocks_from_height(location.block_height as usize).next()?;
+        let block = block.as_v1();
+
+        if location.rejected {
+            block
+                .rejected_transactions
+                .get(location.index)
+                .cloned()
+                .map(Box::new)
+                .map(TransactionValue::RejectedTransaction)
+        } else {
+            block
+                .transactions
+                .get(location.index)
+                .cloned()
+                .map(VersionedTransaction::from)
+                .map(Box::new)
+                .map(TransactionValue::Transaction)
+        }
     }
 
     #[cfg(test)]
@@ -720,30 +1550,15 @@ impl WorldStateView {
         account_id: &AccountId,
     ) -> Vec<TransactionValue> {
         let mut transactions = self
-            .blocks
-            .iter()
-            .flat_map(|block_entry| {
-                let block = block_entry.value().as_v1();
-                block
-                    .rejected_transactions
+            .transactions_by_account
+            .get(account_id)
+            .map(|hashes| {
+                hashes
                     .iter()
-                    .filter(|transaction| &transaction.payload().account_id == account_id)
-                    .cloned()
-                    .map(Box::new)
-                    .map(TransactionValue::RejectedTransaction)
-                    .chain(
-                        block
-                            .transactions
-                            .iter()
-                            .filter(|transaction| &transaction.payload().account_id == account_id)
-                            .cloned()
-                            .map(VersionedTransaction::from)
-                            .map(Box::new)
-                            .map(TransactionValue::Transaction),
-                    )
+                    .filter_map(|hash| self.transaction_value_by_hash(hash))
                     .collect::<Vec<_>>()
             })
-            .collect::<Vec<_>>();
+            .unwrap_or_default();
         transactions.sort();
         transactions
     }
@@ -761,6 +1576,20 @@ impl WorldStateView {
         &self.world.triggers
     }
 
+    /// Take a [`StateReadOnly`] snapshot of `world`, for running queries
+    /// without contending with `apply`'s writer. See [`StateReadOnly`]'s
+    /// doc comment for how this differs from a true MVCC structure with
+    /// structural sharing.
+    #[must_use]
+    pub fn state_read_only(&self) -> StateReadOnly {
+        StateReadOnly {
+            domains: self.world.domains.clone(),
+            roles: self.world.roles.clone(),
+            trusted_peers_ids: self.world.trusted_peers_ids.clone(),
+            triggers: self.world.triggers.clone(),
+        }
+    }
+
     /// Get triggers set and modify it with `f`
     ///
     /// Produces trigger event from `f`
@@ -794,8 +1623,62 @@ impl WorldStateView {
     }
 }
 
+/// `IrohaQuery` implementations for transaction/block history queries.
+/// Colocated with [`WorldStateView::transaction_value_by_hash`],
+/// [`WorldStateView::transactions_after_cursor`] and
+/// [`WorldStateView::blocks_after_cursor`] rather than under
+/// `smartcontracts::isi`, since that's where the logic backing them lives.
+pub mod query {
+    use eyre::Result;
+    use iroha_data_model::prelude::*;
+
+    use super::*;
+    use crate::smartcontracts::query::Error;
+
+    impl ValidQuery for FindTransactionByHash {
+        #[iroha_telemetry::metrics(+"find_transaction_by_hash")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let hash = self
+                .hash
+                .evaluate(wsv, &Context::new())
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+            let hash = hash.typed();
+            iroha_logger::trace!(%hash);
+
+            wsv.transaction_value_by_hash(&hash)
+                .ok_or_else(|| Error::Find(Box::new(FindError::Transaction(hash))))
+        }
+    }
+
+    impl ValidQuery for FindAllTransactions {
+        #[iroha_telemetry::metrics(+"find_all_transactions")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let cursor = self
+                .cursor
+                .evaluate(wsv, &Context::new())
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+
+            Ok(wsv.transactions_after_cursor(cursor))
+        }
+    }
+
+    impl ValidQuery for FindAllBlocks {
+        #[iroha_telemetry::metrics(+"find_all_blocks")]
+        fn execute(&self, wsv: &WorldStateView) -> Result<Self::Output, Error> {
+            let cursor = self
+                .cursor
+                .evaluate(wsv, &Context::new())
+                .map_err(|e| Error::Evaluate(e.to_string()))?;
+
+            Ok(wsv.blocks_after_cursor(cursor))
+        }
+    }
+}
+
 /// This module contains all configuration related logic.
 pub mod config {
+    use std::path::PathBuf;
+
     use iroha_config::derive::Configurable;
     use iroha_data_model::{metadata::Limits as MetadataLimits, LengthLimits};
     use serde::{Deserialize, Serialize};
@@ -805,9 +1688,11 @@ pub mod config {
     const DEFAULT_METADATA_LIMITS: MetadataLimits =
         MetadataLimits::new(2_u32.pow(20), 2_u32.pow(12));
     const DEFAULT_IDENT_LENGTH_LIMITS: LengthLimits = LengthLimits::new(1, 2_u32.pow(7));
+    const DEFAULT_SNAPSHOT_CREATE_EVERY: u64 = 0;
+    const DEFAULT_KEEP_RECENT_BLOCKS: u64 = 0;
 
     /// [`WorldStateView`](super::WorldStateView) configuration.
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, Configurable)]
+    #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Configurable)]
     #[config(env_prefix = "WSV_")]
     #[serde(rename_all = "UPPERCASE", default)]
     pub struct Configuration {
@@ -823,6 +1708,17 @@ pub mod config {
         pub ident_length_limits: LengthLimits,
         /// [`WASM Runtime`](wasm::Runtime) configuration
         pub wasm_runtime_config: wasm::config::Configuration,
+        /// Write a snapshot every N blocks at the end of `apply`. `0` disables
+        /// automatic snapshotting.
+        pub snapshot_create_every: u64,
+        /// Path snapshots are written to and read from.
+        pub snapshot_path: PathBuf,
+        /// Number of most recent blocks to keep resident in memory; older
+        /// blocks are offloaded to a [`BlockStorage`](super::BlockStorage)
+        /// if one is configured via [`WorldStateView::set_block_storage`](super::WorldStateView::set_block_storage).
+        /// `0` disables pruning and keeps every block resident, as before
+        /// this setting existed.
+        pub keep_recent_blocks: u64,
     }
 
     impl Default for Configuration {
@@ -834,6 +1730,9 @@ pub mod config {
                 domain_metadata_limits: DEFAULT_METADATA_LIMITS,
                 ident_length_limits: DEFAULT_IDENT_LENGTH_LIMITS,
                 wasm_runtime_config: wasm::config::Configuration::default(),
+                snapshot_create_every: DEFAULT_SNAPSHOT_CREATE_EVERY,
+                snapshot_path: PathBuf::from("./wsv_snapshot.scale"),
+                keep_recent_blocks: DEFAULT_KEEP_RECENT_BLOCKS,
             }
         }
     }
@@ -6,8 +6,10 @@
 //! the Genesis block.
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{boxed::Box, collections::btree_map, format, string::String, vec::Vec};
 use core::{cmp::Ordering, str::FromStr};
+#[cfg(feature = "std")]
+use std::collections::btree_map;
 
 use derive_more::{Display, FromStr};
 use getset::{Getters, MutGetters};
@@ -23,7 +25,7 @@ use crate::{
     asset::AssetDefinitionsMap,
     metadata::Metadata,
     prelude::{AssetDefinition, AssetDefinitionEntry},
-    HasMetadata, Identifiable, Name, ParseError, Registered,
+    HasMetadata, Identifiable, LengthLimits, Name, ParseError, Registered, Value,
 };
 
 /// The domain name of the genesis domain.
@@ -31,6 +33,17 @@ use crate::{
 /// The genesis domain should only contain the genesis account.
 pub const GENESIS_DOMAIN_NAME: &str = "genesis";
 
+/// Reserved [`Domain`] metadata key under which a domain may override the
+/// global [`LengthLimits`] applied to identifiers registered within its
+/// own subtree (e.g. new account/asset-definition names), so that a
+/// domain can tighten or loosen its own limits instead of always using
+/// whatever the peer's static configuration says. Encoded as a
+/// `"min,max"` string; read back via [`Domain::ident_length_limits_override`].
+/// Setting it is guarded by the `CanSetDomainMetadataLimits` permission
+/// token and may only narrow, never widen, the global ceiling - see
+/// `permissions_validators::public_blockchain::domain`.
+pub const IDENT_LENGTH_LIMITS_OVERRIDE_KEY: &str = "iroha.ident_length_limits_override";
+
 /// Genesis domain. It will contain only one `genesis` account.
 #[derive(Debug, Decode, Encode, Deserialize, Serialize, IntoSchema)]
 pub struct GenesisDomain {
@@ -49,11 +62,6 @@ impl GenesisDomain {
 #[cfg(feature = "mutable_api")]
 impl From<GenesisDomain> for Domain {
     fn from(domain: GenesisDomain) -> Self {
-        #[cfg(not(feature = "std"))]
-        use alloc::collections::btree_map;
-        #[cfg(feature = "std")]
-        use std::collections::btree_map;
-
         #[allow(clippy::expect_used)]
         Self {
             id: Id::from_str(GENESIS_DOMAIN_NAME).expect("Valid"),
@@ -64,7 +72,8 @@ impl From<GenesisDomain> for Domain {
             .collect(),
             asset_definitions: btree_map::BTreeMap::default(),
             metadata: Metadata::default(),
-            logo: None,
+            resources: btree_map::BTreeMap::default(),
+            capabilities: Vec::new(),
         }
     }
 }
@@ -78,10 +87,13 @@ impl From<GenesisDomain> for Domain {
 pub struct NewDomain {
     /// The identification associated to the domain builder.
     id: <Domain as Identifiable>::Id,
-    /// The (IPFS) link to the logo of this domain.
-    logo: Option<IpfsPath>,
+    /// Named, integrity-verified content links (e.g. `"logo"`, `"terms"`)
+    /// associated to the domain builder.
+    resources: btree_map::BTreeMap<Name, ContentLink>,
     /// metadata associated to the domain builder.
     metadata: Metadata,
+    /// Root [`CapabilityToken`] delegations seeded for this domain.
+    capabilities: Vec<CapabilityToken>,
 }
 
 impl HasMetadata for NewDomain {
@@ -111,8 +123,9 @@ impl NewDomain {
     fn new(id: <Domain as Identifiable>::Id) -> Self {
         Self {
             id,
-            logo: None,
+            resources: btree_map::BTreeMap::default(),
             metadata: Metadata::default(),
+            capabilities: Vec::new(),
         }
     }
 
@@ -125,18 +138,32 @@ impl NewDomain {
             accounts: AccountsMap::default(),
             asset_definitions: AssetDefinitionsMap::default(),
             metadata: self.metadata,
-            logo: self.logo,
+            resources: self.resources,
+            capabilities: self.capabilities,
         }
     }
 }
 
 #[cfg_attr(feature = "ffi_api", ffi_bindgen)]
 impl NewDomain {
+    /// Add a named, integrity-verified content link to the domain,
+    /// replacing any previously defined under the same `name`. The expected
+    /// digest is taken from `path`'s own CID.
+    ///
+    /// # Errors
+    /// Fails if `path` doesn't resolve through a CID (e.g. an IPNS DNSLink
+    /// name), since there is then nothing to verify fetched content against.
+    pub fn with_resource(mut self, name: Name, path: IpfsPath) -> Result<Self, ParseError> {
+        self.resources.insert(name, ContentLink::new(path)?);
+        Ok(self)
+    }
+
     /// Add [`logo`](IpfsPath) to the domain replacing previously defined value
-    #[must_use]
-    pub fn with_logo(mut self, logo: IpfsPath) -> Self {
-        self.logo = Some(logo);
-        self
+    ///
+    /// # Errors
+    /// Fails if `logo` doesn't resolve through a CID; see [`Self::with_resource`].
+    pub fn with_logo(self, logo: IpfsPath) -> Result<Self, ParseError> {
+        self.with_resource(logo_name(), logo)
     }
 
     /// Add [`Metadata`] to the domain replacing previously defined value
@@ -145,6 +172,15 @@ impl NewDomain {
         self.metadata = metadata;
         self
     }
+
+    /// Seed a root [`CapabilityToken`] delegation, appended to any already
+    /// recorded. [`Domain::verify_capability`] trusts a chain only if it
+    /// bottoms out in one of these.
+    #[must_use]
+    pub fn with_root_capability(mut self, token: CapabilityToken) -> Self {
+        self.capabilities.push(token);
+        self
+    }
 }
 
 impl Identifiable for NewDomain {
@@ -155,6 +191,238 @@ impl Identifiable for NewDomain {
     }
 }
 
+/// A single attenuable permission grant: the ability to perform `ability`
+/// against `resource`.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct Capability {
+    /// The domain-scoped entity this capability applies to.
+    pub resource: CapabilityResource,
+    /// The action permitted, e.g. `"asset:mint"` or `"account:register"`.
+    /// A parent capability may grant a `"<namespace>:*"` wildcard that
+    /// covers every ability under that namespace.
+    pub ability: String,
+}
+
+impl Capability {
+    /// Returns `true` if `self` is no broader than `parent`: its resource is
+    /// covered by `parent`'s, and its ability is the same as, or a
+    /// namespaced sub-action of, `parent`'s.
+    #[must_use]
+    pub fn is_attenuation_of(&self, parent: &Self) -> bool {
+        self.resource.is_covered_by(&parent.resource) && ability_is_covered_by(&self.ability, &parent.ability)
+    }
+}
+
+/// Returns `true` if `ability` is `parent` itself, or `parent` is a
+/// `"<namespace>:*"` wildcard and `ability` names an action in that
+/// namespace.
+fn ability_is_covered_by(ability: &str, parent: &str) -> bool {
+    if ability == parent {
+        return true;
+    }
+    parent
+        .strip_suffix('*')
+        .and_then(|namespace| ability.strip_prefix(namespace))
+        .is_some()
+}
+
+/// The entity a [`Capability`] grants authority over.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub enum CapabilityResource {
+    /// Every resource within the domain.
+    Domain,
+    /// A single asset definition within the domain.
+    Asset(<AssetDefinition as Identifiable>::Id),
+    /// A single account within the domain.
+    Account(<Account as Identifiable>::Id),
+}
+
+impl CapabilityResource {
+    /// Returns `true` if `self` is the same resource as `parent`, or
+    /// `parent` is the whole-domain wildcard.
+    #[must_use]
+    pub fn is_covered_by(&self, parent: &Self) -> bool {
+        parent == &Self::Domain || self == parent
+    }
+}
+
+/// A signed, attenuating delegation of one or more [`Capability`]s from
+/// `issuer` to `audience`, optionally derived from a broader `parent`
+/// delegation.
+///
+/// Chains of `CapabilityToken`s let [`Domain::verify_capability`] validate a
+/// grant offline, without consulting a central authority: anyone holding the
+/// chain from a leaf token back to a root recorded on the `Domain` can check
+/// it themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Getters, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct CapabilityToken {
+    /// Who is granting authority.
+    #[getset(get = "pub")]
+    issuer: PublicKey,
+    /// Who receives it.
+    #[getset(get = "pub")]
+    audience: PublicKey,
+    /// What is being granted.
+    #[getset(get = "pub")]
+    capabilities: Vec<Capability>,
+    /// Unix timestamp (seconds) before which this token is not yet valid.
+    #[getset(get = "pub")]
+    not_before: Option<u64>,
+    /// Unix timestamp (seconds) after which this token is no longer valid.
+    #[getset(get = "pub")]
+    expiry: Option<u64>,
+    /// The delegation this token attenuates, if any. `None` marks a root
+    /// delegation, which must be anchored on the owning [`Domain`] to be
+    /// trusted.
+    ///
+    /// Stored inline rather than as a hash pointer into a content-addressed
+    /// store, since this codebase doesn't have one yet; a future revision
+    /// may replace this with a lookup by digest.
+    #[getset(get = "pub")]
+    parent: Option<Box<CapabilityToken>>,
+    /// Signature over [`CapabilityToken::payload_to_sign`] by `issuer`.
+    #[getset(get = "pub")]
+    signature: iroha_crypto::Signature,
+}
+
+impl CapabilityToken {
+    /// Builds the byte payload that `issuer` signs to authorize this token.
+    #[must_use]
+    pub fn payload_to_sign(
+        issuer: &PublicKey,
+        audience: &PublicKey,
+        capabilities: &[Capability],
+        not_before: Option<u64>,
+        expiry: Option<u64>,
+        parent: Option<&CapabilityToken>,
+    ) -> Vec<u8> {
+        (issuer, audience, capabilities, not_before, expiry, parent).encode()
+    }
+
+    /// Creates a new token from an already-computed `signature`.
+    ///
+    /// # Errors
+    /// Fails if `signature` was not produced by `issuer` over the payload
+    /// derived from the other fields.
+    pub fn new(
+        issuer: PublicKey,
+        audience: PublicKey,
+        capabilities: Vec<Capability>,
+        not_before: Option<u64>,
+        expiry: Option<u64>,
+        parent: Option<Box<CapabilityToken>>,
+        signature: iroha_crypto::Signature,
+    ) -> Result<Self, ParseError> {
+        let payload = Self::payload_to_sign(
+            &issuer,
+            &audience,
+            &capabilities,
+            not_before,
+            expiry,
+            parent.as_deref(),
+        );
+        if signature.public_key() != &issuer || signature.verify(&payload).is_err() {
+            return Err(ParseError {
+                reason: "Capability token signature does not verify",
+            });
+        }
+        Ok(Self {
+            issuer,
+            audience,
+            capabilities,
+            not_before,
+            expiry,
+            parent,
+            signature,
+        })
+    }
+
+    /// Whether [`Self::signature`] is a valid signature by [`Self::issuer`]
+    /// over this token's own fields. [`Self::new`] checks this once at
+    /// construction time, but a chain decoded straight off the wire (via
+    /// `Decode`/`Deserialize`) skips that constructor entirely, so
+    /// [`Domain::verify_capability`] must re-check it at every hop instead
+    /// of trusting that a chain it didn't build itself was actually signed.
+    #[must_use]
+    pub fn has_valid_signature(&self) -> bool {
+        let payload = Self::payload_to_sign(
+            &self.issuer,
+            &self.audience,
+            &self.capabilities,
+            self.not_before,
+            self.expiry,
+            self.parent.as_deref(),
+        );
+        self.signature.public_key() == &self.issuer && self.signature.verify(&payload).is_ok()
+    }
+}
+
+/// Returns `true` if `child`'s validity window nests inside `parent`'s:
+/// `child`'s `not_before` is no earlier and its `expiry` no later, treating
+/// an absent bound as unbounded on that side.
+fn time_bounds_nest(child: &CapabilityToken, parent: &CapabilityToken) -> bool {
+    let not_before_ok = match (*child.not_before(), *parent.not_before()) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(child_nbf), Some(parent_nbf)) => child_nbf >= parent_nbf,
+    };
+    let expiry_ok = match (*child.expiry(), *parent.expiry()) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(child_exp), Some(parent_exp)) => child_exp <= parent_exp,
+    };
+    not_before_ok && expiry_ok
+}
+
+/// An [`IpfsPath`] paired with the content digest its root CID commits to,
+/// so a client that fetches the off-chain blob can confirm it matches what
+/// the domain committed on-chain.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema)]
+pub struct ContentLink {
+    /// Where to fetch the content.
+    path: IpfsPath,
+    /// The multihash digest (assumed sha2-256) `path`'s root CID commits to.
+    digest: Vec<u8>,
+}
+
+impl ContentLink {
+    /// Builds a `ContentLink` from `path`, taking the expected digest from
+    /// its root CID.
+    ///
+    /// # Errors
+    /// Fails if `path`'s root doesn't resolve through a CID (e.g. an IPNS
+    /// DNSLink name), since there is then nothing to verify content against.
+    pub fn new(path: IpfsPath) -> Result<Self, ParseError> {
+        let digest = path.root_digest().ok_or(ParseError {
+            reason: "IPFS path has no content-addressed root to verify against",
+        })?;
+        Ok(Self { path, digest })
+    }
+
+    /// The path this link points at.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &IpfsPath {
+        &self.path
+    }
+
+    /// Returns `true` if `bytes` hashes (sha2-256) to the digest this link
+    /// commits to.
+    #[must_use]
+    pub fn verify(&self, bytes: &[u8]) -> bool {
+        use sha2::{Digest as _, Sha256};
+
+        Sha256::digest(bytes).as_slice() == self.digest.as_slice()
+    }
+}
+
+/// The conventional [`Name`] under which a domain's logo is stored in its
+/// `resources` map.
+#[allow(clippy::expect_used)]
+fn logo_name() -> Name {
+    Name::from_str("logo").expect("\"logo\" is a valid Name")
+}
+
 /// Named group of [`Account`] and [`Asset`](`crate::asset::Asset`) entities.
 #[derive(
     Debug,
@@ -180,12 +448,17 @@ pub struct Domain {
     accounts: AccountsMap,
     /// [`Asset`](AssetDefinition)s defined of the `Domain`.
     asset_definitions: AssetDefinitionsMap,
-    /// IPFS link to the `Domain` logo
-    #[getset(get = "pub")]
-    logo: Option<IpfsPath>,
+    /// Named, integrity-verified content links (e.g. `"logo"`, `"terms"`)
+    /// associated to the `Domain`. See [`Domain::resource`] and
+    /// [`Domain::logo`].
+    resources: btree_map::BTreeMap<Name, ContentLink>,
     /// [`Metadata`] of this `Domain` as a key-value store.
     #[cfg_attr(feature = "mutable_api", getset(get_mut = "pub"))]
     metadata: Metadata,
+    /// Root [`CapabilityToken`] delegations anchored on this domain, against
+    /// which [`Domain::verify_capability`] checks delegation chains.
+    #[getset(get = "pub")]
+    capabilities: Vec<CapabilityToken>,
 }
 
 impl HasMetadata for Domain {
@@ -228,6 +501,21 @@ impl Domain {
         <Self as Registered>::With::new(id)
     }
 
+    /// This domain's [`LengthLimits`] override for identifiers registered
+    /// within its own subtree, read from its own metadata under
+    /// [`IDENT_LENGTH_LIMITS_OVERRIDE_KEY`]. `None` if the domain hasn't
+    /// set one or the stored value isn't a valid `"min,max"` pair, in
+    /// which case the caller should fall back to the global default.
+    pub fn ident_length_limits_override(&self) -> Option<LengthLimits> {
+        let key: Name = IDENT_LENGTH_LIMITS_OVERRIDE_KEY.parse().ok()?;
+        let raw = match self.metadata.get(&key)? {
+            Value::String(raw) => raw,
+            _ => return None,
+        };
+        let (min, max) = raw.split_once(',')?;
+        Some(LengthLimits::new(min.parse().ok()?, max.parse().ok()?))
+    }
+
     /// Return a reference to the [`Account`] corresponding to the account id.
     #[inline]
     pub fn account(&self, account_id: &<Account as Identifiable>::Id) -> Option<&Account> {
@@ -260,6 +548,73 @@ impl Domain {
     pub fn asset_definitions(&self) -> impl ExactSizeIterator<Item = &AssetDefinitionEntry> {
         self.asset_definitions.values()
     }
+
+    /// Return a reference to the named content link, if any.
+    #[inline]
+    pub fn resource(&self, name: &Name) -> Option<&ContentLink> {
+        self.resources.get(name)
+    }
+
+    /// IPFS link to the `Domain` logo, i.e. the `"logo"` [`ContentLink`].
+    #[inline]
+    #[must_use]
+    pub fn logo(&self) -> Option<&IpfsPath> {
+        self.resource(&logo_name()).map(ContentLink::path)
+    }
+
+    /// Returns `true` if `token`, walked back through its `parent` chain,
+    /// grants `ability` over `resource` and bottoms out in one of this
+    /// domain's recorded root delegations.
+    ///
+    /// At every hop from a child token to its parent, all of the following
+    /// must hold, or the chain is rejected:
+    /// - the child's audience equals the parent's issuer;
+    /// - every capability on the child is an attenuation
+    ///   ([`Capability::is_attenuation_of`]) of some capability on the
+    ///   parent;
+    /// - the child's validity window nests inside the parent's.
+    #[must_use]
+    pub fn verify_capability(
+        &self,
+        token: &CapabilityToken,
+        ability: &str,
+        resource: &CapabilityResource,
+    ) -> bool {
+        let leaf_grants = token.capabilities().iter().any(|capability| {
+            resource.is_covered_by(&capability.resource)
+                && ability_is_covered_by(ability, &capability.ability)
+        });
+        if !leaf_grants {
+            return false;
+        }
+
+        let mut link = token;
+        loop {
+            if !link.has_valid_signature() {
+                return false;
+            }
+
+            let Some(parent) = link.parent().as_deref() else {
+                break;
+            };
+
+            if link.audience() != parent.issuer() {
+                return false;
+            }
+            let attenuates = link.capabilities().iter().all(|capability| {
+                parent
+                    .capabilities()
+                    .iter()
+                    .any(|parent_capability| capability.is_attenuation_of(parent_capability))
+            });
+            if !attenuates || !time_bounds_nest(link, parent) {
+                return false;
+            }
+            link = parent;
+        }
+
+        self.capabilities.contains(link)
+    }
 }
 
 #[cfg(feature = "mutable_api")]
@@ -360,7 +715,9 @@ impl FromStr for IpfsPath {
 
             match root_type {
                 "ipfs" | "ipld" => Self::check_cid(key)?,
-                "ipns" => (),
+                "ipns" => {
+                    Self::ipns_root_kind(key)?;
+                }
                 _ => {
                     return Err(ParseError {
                         reason: "Unexpected root type. Expected `ipfs`, `ipld` or `ipns`",
@@ -373,7 +730,7 @@ impl FromStr for IpfsPath {
         }
 
         for path in subpath {
-            Self::check_cid(path)?;
+            Self::check_path_segment(path)?;
         }
 
         Ok(IpfsPath(String::from(string)))
@@ -387,13 +744,124 @@ impl AsRef<str> for IpfsPath {
     }
 }
 
+/// Which kind of content an [`IpfsPath`]'s root resolves through, so a
+/// resolver can choose a DHT lookup (`Ipfs`/`Ipld`/`IpnsKey`) versus a DNS
+/// TXT lookup (`IpnsDnsLink`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IpfsRootKind {
+    /// `/ipfs/<cid>`: immutable content-addressed data.
+    Ipfs,
+    /// `/ipld/<cid>`: immutable linked data.
+    Ipld,
+    /// `/ipns/<cid>`, where `<cid>` is a CIDv1 with the `libp2p-key` codec:
+    /// a mutable record resolved by looking up `<cid>` in the DHT.
+    IpnsKey,
+    /// `/ipns/<name>`, where `<name>` is a DNSLink domain name: a mutable
+    /// record resolved by a DNS `TXT` lookup on `_dnslink.<name>`.
+    IpnsDnsLink,
+    /// No explicit root prefix; treated the same as `Ipfs`.
+    Unprefixed,
+}
+
 impl IpfsPath {
-    /// Superficially checks IPFS `cid` (Content Identifier)
+    /// Validates a content-root segment (e.g. the `<cid>` in `/ipfs/<cid>`)
+    /// as a real multiformats CID, either v0 or v1.
     #[inline]
-    const fn check_cid(cid: &str) -> Result<(), ParseError> {
-        if cid.len() < 2 {
+    fn check_cid(cid: &str) -> Result<(), ParseError> {
+        cid::validate(cid)
+    }
+
+    /// Validates an IPNS root segment (the `<key>` in `/ipns/<key>`): either
+    /// a CIDv1 with the `libp2p-key` multicodec, or a DNSLink name.
+    fn ipns_root_kind(key: &str) -> Result<IpfsRootKind, ParseError> {
+        // A base32 CIDv1 can look like a valid DNS label, so it must be
+        // tried as a CID first; only fall back to DNSLink once CID parsing
+        // rules it out.
+        if let Ok(codec) = cid::parse_v1(key) {
+            return if codec == cid::MULTICODEC_LIBP2P_KEY {
+                Ok(IpfsRootKind::IpnsKey)
+            } else {
+                Err(ParseError {
+                    reason: "IPNS root must be a libp2p-key CIDv1 or a DNSLink name",
+                })
+            };
+        }
+
+        if is_dnslink_name(key) {
+            Ok(IpfsRootKind::IpnsDnsLink)
+        } else {
+            Err(ParseError {
+                reason: "IPNS root must be a libp2p-key CIDv1 or a DNSLink name",
+            })
+        }
+    }
+
+    /// Which kind of root this path resolves through.
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    pub fn root_kind(&self) -> IpfsRootKind {
+        let mut parts = self.0.splitn(3, '/');
+        // `IpfsPath` can only be constructed via `FromStr::from_str`, which
+        // already validated the root, so re-deriving its kind here always
+        // succeeds.
+        let first = parts.next().expect("split always yields at least one item");
+
+        if first.is_empty() {
+            let root_type = parts.next().expect("root validated at construction");
+            let key = parts
+                .next()
+                .expect("root validated at construction")
+                .split('/')
+                .next()
+                .expect("split always yields at least one item");
+
+            match root_type {
+                "ipfs" => IpfsRootKind::Ipfs,
+                "ipld" => IpfsRootKind::Ipld,
+                "ipns" => Self::ipns_root_kind(key).expect("root validated at construction"),
+                _ => unreachable!("root type validated at construction"),
+            }
+        } else {
+            IpfsRootKind::Unprefixed
+        }
+    }
+
+    /// The multihash digest carried by this path's root CID, if it resolves
+    /// through one. An IPNS DNSLink name (`/ipns/example.com`) isn't a CID
+    /// and has no digest to extract.
+    #[must_use]
+    #[allow(clippy::expect_used)]
+    pub fn root_digest(&self) -> Option<Vec<u8>> {
+        if self.root_kind() == IpfsRootKind::IpnsDnsLink {
+            return None;
+        }
+
+        let mut parts = self.0.splitn(3, '/');
+        let first = parts.next().expect("split always yields at least one item");
+
+        let key = if first.is_empty() {
+            parts.next().expect("root validated at construction");
+            parts
+                .next()
+                .expect("root validated at construction")
+                .split('/')
+                .next()
+                .expect("split always yields at least one item")
+        } else {
+            first
+        };
+
+        cid::digest(key).ok()
+    }
+
+    /// Validates a human-readable path segment after the content root (e.g.
+    /// `sub`/`paths` in `/ipfs/<cid>/sub/paths`). These aren't CIDs, so they
+    /// only need to be non-empty.
+    #[inline]
+    const fn check_path_segment(segment: &str) -> Result<(), ParseError> {
+        if segment.is_empty() {
             return Err(ParseError {
-                reason: "IPFS cid is too short",
+                reason: "IPFS path segment is empty",
             });
         }
 
@@ -401,6 +869,243 @@ impl IpfsPath {
     }
 }
 
+/// Checks whether `name` is a valid DNSLink name: a dot-separated sequence
+/// of DNS labels, each 1-63 characters of `[a-z0-9-]`, not starting or
+/// ending with `-`, with the whole name at most 253 characters.
+fn is_dnslink_name(name: &str) -> bool {
+    if name.is_empty() || name.len() > 253 {
+        return false;
+    }
+
+    name.split('.').all(|label| {
+        let bytes = label.as_bytes();
+        !bytes.is_empty()
+            && bytes.len() <= 63
+            && bytes[0] != b'-'
+            && bytes[bytes.len() - 1] != b'-'
+            && bytes
+                .iter()
+                .all(|&b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+    })
+}
+
+/// Minimal multiformats CID (v0/v1) validation.
+///
+/// Hand-rolled rather than pulling in `bs58`/`multibase`/`multihash`, so
+/// `IpfsPath` validation stays `no_std`-friendly: every decoder here is
+/// small and allocates only the output buffer it needs.
+mod cid {
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::String, vec::Vec};
+
+    use crate::ParseError;
+
+    const BASE58BTC_ALPHABET: &[u8] =
+        b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+    /// sha2-256 multihash function code.
+    const MULTIHASH_SHA2_256: u8 = 0x12;
+    /// `libp2p-key` multicodec, used by IPNS CIDs.
+    pub(super) const MULTICODEC_LIBP2P_KEY: u64 = 0x72;
+
+    /// Validates `cid` as either a CIDv0 or a CIDv1.
+    pub(super) fn validate(cid: &str) -> Result<(), ParseError> {
+        if cid.starts_with("Qm") {
+            check_v0(cid)
+        } else {
+            parse_v1(cid).map(|_codec| ())
+        }
+    }
+
+    /// Returns the raw multihash digest bytes `cid` (CIDv0 or CIDv1)
+    /// commits to, so callers can check fetched content against it.
+    pub(super) fn digest(cid: &str) -> Result<Vec<u8>, ParseError> {
+        if cid.starts_with("Qm") {
+            check_v0(cid)?;
+            Ok(decode_base58btc(cid)?[2..].to_vec())
+        } else {
+            parse_v1_with_digest(cid).map(|(_codec, digest)| digest)
+        }
+    }
+
+    /// CIDv0: a raw base58btc-encoded sha2-256 multihash, 34 bytes long.
+    fn check_v0(cid: &str) -> Result<(), ParseError> {
+        let bytes = decode_base58btc(cid)?;
+
+        if bytes.len() != 34 {
+            return Err(ParseError {
+                reason: "CIDv0 must decode to exactly 34 bytes",
+            });
+        }
+        if bytes[0] != MULTIHASH_SHA2_256 {
+            return Err(ParseError {
+                reason: "CIDv0 must use the sha2-256 multihash function (code 0x12)",
+            });
+        }
+        if bytes[1] != 0x20 {
+            return Err(ParseError {
+                reason: "CIDv0 digest length must be 32 bytes (0x20)",
+            });
+        }
+
+        Ok(())
+    }
+
+    /// CIDv1: `<multibase-prefix><version-varint><codec-varint><multihash>`.
+    /// Returns the multicodec so callers (e.g. IPNS root validation) can
+    /// inspect it without re-parsing.
+    pub(super) fn parse_v1(cid: &str) -> Result<u64, ParseError> {
+        parse_v1_with_digest(cid).map(|(codec, _digest)| codec)
+    }
+
+    /// As [`parse_v1`], but also returns the multihash digest bytes.
+    fn parse_v1_with_digest(cid: &str) -> Result<(u64, Vec<u8>), ParseError> {
+        let mut chars = cid.chars();
+        let prefix = chars.next().ok_or(ParseError {
+            reason: "CID is empty",
+        })?;
+        let rest: String = chars.collect();
+
+        let bytes = match prefix {
+            'b' => decode_base32(&rest)?,
+            'z' => decode_base58btc(&rest)?,
+            'f' => decode_base16(&rest)?,
+            _ => {
+                return Err(ParseError {
+                    reason: "Unsupported or unknown CIDv1 multibase prefix",
+                })
+            }
+        };
+
+        let (version, rest) = read_varint(&bytes)?;
+        if version != 1 {
+            return Err(ParseError {
+                reason: "CIDv1 version varint must be 1",
+            });
+        }
+
+        let (codec, rest) = read_varint(rest)?;
+        let (_hash_code, rest) = read_varint(rest)?;
+        let (digest_len, rest) = read_varint(rest)?;
+
+        if rest.len() as u64 != digest_len {
+            return Err(ParseError {
+                reason: "CIDv1 multihash digest length doesn't match the remaining bytes",
+            });
+        }
+
+        Ok((codec, rest.to_vec()))
+    }
+
+    /// Reads an unsigned LEB128 varint (as used throughout multiformats)
+    /// from the front of `input`, returning the value and the rest.
+    fn read_varint(input: &[u8]) -> Result<(u64, &[u8]), ParseError> {
+        let mut value: u64 = 0;
+        for (i, &byte) in input.iter().enumerate() {
+            let shift = 7 * i as u32;
+            if shift >= 64 {
+                return Err(ParseError {
+                    reason: "Varint is too long",
+                });
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok((value, &input[i + 1..]));
+            }
+        }
+
+        Err(ParseError {
+            reason: "Truncated varint",
+        })
+    }
+
+    /// Decodes a base58btc string into bytes, preserving leading zero bytes
+    /// the usual way: each leading `1` in the input maps to one `0x00` byte.
+    fn decode_base58btc(input: &str) -> Result<Vec<u8>, ParseError> {
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for c in input.chars() {
+            let digit = BASE58BTC_ALPHABET
+                .iter()
+                .position(|&b| b as char == c)
+                .ok_or(ParseError {
+                    reason: "Invalid base58btc character",
+                })? as u32;
+
+            let mut carry = digit;
+            for byte in &mut bytes {
+                let x = u32::from(*byte) * 58 + carry;
+                *byte = (x & 0xff) as u8;
+                carry = x >> 8;
+            }
+            while carry > 0 {
+                bytes.push((carry & 0xff) as u8);
+                carry >>= 8;
+            }
+        }
+        bytes.reverse();
+
+        let leading_ones = input.chars().take_while(|&c| c == '1').count();
+        let mut result = Vec::with_capacity(leading_ones + bytes.len());
+        result.resize(leading_ones, 0);
+        result.extend_from_slice(&bytes);
+        Ok(result)
+    }
+
+    /// Decodes a lower-case, unpadded RFC4648 base32 string (multibase `b`).
+    fn decode_base32(input: &str) -> Result<Vec<u8>, ParseError> {
+        let mut bits: u64 = 0;
+        let mut bit_count = 0_u32;
+        let mut out = Vec::new();
+
+        for c in input.chars() {
+            let value = u64::from(
+                BASE32_ALPHABET
+                    .iter()
+                    .position(|&b| b as char == c)
+                    .ok_or(ParseError {
+                        reason: "Invalid base32 character",
+                    })? as u32,
+            );
+            bits = (bits << 5) | value;
+            bit_count += 5;
+            if bit_count >= 8 {
+                bit_count -= 8;
+                out.push(((bits >> bit_count) & 0xff) as u8);
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Decodes a lower-case base16/hex string (multibase `f`).
+    fn decode_base16(input: &str) -> Result<Vec<u8>, ParseError> {
+        let bytes = input.as_bytes();
+        if bytes.len() % 2 != 0 {
+            return Err(ParseError {
+                reason: "Base16 input must have an even length",
+            });
+        }
+
+        let hex_digit = |c: u8| -> Result<u8, ParseError> {
+            match c {
+                b'0'..=b'9' => Ok(c - b'0'),
+                b'a'..=b'f' => Ok(c - b'a' + 10),
+                _ => Err(ParseError {
+                    reason: "Invalid base16 character",
+                }),
+            }
+        };
+
+        let mut out = Vec::with_capacity(bytes.len() / 2);
+        for chunk in bytes.chunks(2) {
+            out.push((hex_digit(chunk[0])? << 4) | hex_digit(chunk[1])?);
+        }
+        Ok(out)
+    }
+}
+
 impl<'de> Deserialize<'de> for IpfsPath {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -489,7 +1194,7 @@ mod tests {
         ));
         assert!(matches!(
             IpfsPath::from_str(INVALID_IPFS[2]),
-            Err(err) if err.to_string() == "IPFS cid is too short"
+            Err(err) if err.to_string() == "Unsupported or unknown CIDv1 multibase prefix"
         ));
         assert!(matches!(
             IpfsPath::from_str(INVALID_IPFS[3]),
@@ -507,10 +1212,46 @@ mod tests {
             .expect("Path with ipfs root should be valid");
         IpfsPath::from_str("/ipld/QmQqzMTavQgT4f4T5v6PWBp7XNKtoPmC9jvn12WPT3gkSE")
             .expect("Path with ipld root should be valid");
-        IpfsPath::from_str("/ipns/QmSrPmbaUKA3ZodhzPWZnpFgcPMFWF4QsxXbkWfEptTBJd")
-            .expect("Path with ipns root should be valid");
-        IpfsPath::from_str("/ipfs/SomeFolder/SomeImage")
-            .expect("Path with folders should be valid");
+        IpfsPath::from_str("/ipns/bafzbeidcv6dqi5spv6hkql6gdtu4jq4qrnwls7kghjru5hsypv6iqxnq54")
+            .expect("Path with a libp2p-key ipns root should be valid");
+        IpfsPath::from_str("/ipns/example.com")
+            .expect("Path with a DNSLink ipns root should be valid");
+        IpfsPath::from_str("/ipfs/QmQqzMTavQgT4f4T5v6PWBp7XNKtoPmC9jvn12WPT3gkSE/SomeFolder/SomeImage")
+            .expect("Human-readable subpaths after a valid root CID should be valid");
+    }
+
+    #[test]
+    fn test_cidv1_ipfs_path() {
+        // `bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi` is the
+        // well-known CIDv1 for an empty UnixFS directory (base32, dag-pb).
+        IpfsPath::from_str("/ipfs/bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi")
+            .expect("Valid CIDv1 should be accepted");
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn test_ipns_root_kind() {
+        assert_eq!(
+            IpfsPath::from_str("/ipns/bafzbeidcv6dqi5spv6hkql6gdtu4jq4qrnwls7kghjru5hsypv6iqxnq54")
+                .expect("Valid")
+                .root_kind(),
+            IpfsRootKind::IpnsKey
+        );
+        assert_eq!(
+            IpfsPath::from_str("/ipns/example.com")
+                .expect("Valid")
+                .root_kind(),
+            IpfsRootKind::IpnsDnsLink
+        );
+        assert_eq!(
+            IpfsPath::from_str("/ipfs/QmQqzMTavQgT4f4T5v6PWBp7XNKtoPmC9jvn12WPT3gkSE")
+                .expect("Valid")
+                .root_kind(),
+            IpfsRootKind::Ipfs
+        );
+
+        assert!(IpfsPath::from_str("/ipns/QmSrPmbaUKA3ZodhzPWZnpFgcPMFWF4QsxXbkWfEptTBJd").is_err());
+        assert!(IpfsPath::from_str("/ipns/Not_A-Valid.Domain").is_err());
     }
 
     #[test]
@@ -534,4 +1275,105 @@ mod tests {
             assert!(ipfs.is_err());
         }
     }
+
+    fn asset_resource(name: &str) -> CapabilityResource {
+        CapabilityResource::Asset(
+            format!("rose#{name}")
+                .parse()
+                .expect("Valid asset definition id"),
+        )
+    }
+
+    #[test]
+    fn capability_attenuation_narrows_resource_and_ability() {
+        let parent = Capability {
+            resource: CapabilityResource::Domain,
+            ability: "asset:*".to_owned(),
+        };
+        let scoped_mint = Capability {
+            resource: asset_resource("wonderland"),
+            ability: "asset:mint".to_owned(),
+        };
+        assert!(scoped_mint.is_attenuation_of(&parent));
+
+        let unrelated_ability = Capability {
+            resource: asset_resource("wonderland"),
+            ability: "account:register".to_owned(),
+        };
+        assert!(!unrelated_ability.is_attenuation_of(&parent));
+
+        let broader_resource = Capability {
+            resource: CapabilityResource::Domain,
+            ability: "asset:mint".to_owned(),
+        };
+        assert!(!broader_resource.is_attenuation_of(&scoped_mint));
+    }
+
+    #[test]
+    fn time_bounds_must_nest_inside_parent() {
+        let root = CapabilityToken {
+            issuer: PublicKey::default(),
+            audience: PublicKey::default(),
+            capabilities: Vec::new(),
+            not_before: Some(10),
+            expiry: Some(20),
+            parent: None,
+            signature: iroha_crypto::Signature::default(),
+        };
+        let nested = CapabilityToken {
+            not_before: Some(12),
+            expiry: Some(15),
+            ..root.clone()
+        };
+        assert!(time_bounds_nest(&nested, &root));
+
+        let overruns_start = CapabilityToken {
+            not_before: Some(5),
+            ..root.clone()
+        };
+        assert!(!time_bounds_nest(&overruns_start, &root));
+
+        let overruns_end = CapabilityToken {
+            expiry: Some(25),
+            ..root
+        };
+        assert!(!time_bounds_nest(&overruns_end, &root));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn content_link_verifies_matching_bytes_and_rejects_others() {
+        let path = IpfsPath::from_str("/ipfs/QmQqzMTavQgT4f4T5v6PWBp7XNKtoPmC9jvn12WPT3gkSE")
+            .expect("Valid");
+        let link = ContentLink::new(path).expect("Has a content-addressed root");
+
+        assert!(!link.verify(b"definitely not the linked content"));
+
+        use sha2::Digest as _;
+
+        let content = b"linked content";
+        let matching = ContentLink {
+            path: link.path().clone(),
+            digest: sha2::Sha256::digest(content).to_vec(),
+        };
+        assert!(matching.verify(content));
+    }
+
+    #[test]
+    #[allow(clippy::expect_used)]
+    fn domain_logo_reads_back_through_resources() {
+        let domain = Domain::new(Id::from_str("wonderland").expect("Valid"))
+            .with_logo(
+                IpfsPath::from_str("/ipfs/QmQqzMTavQgT4f4T5v6PWBp7XNKtoPmC9jvn12WPT3gkSE")
+                    .expect("Valid"),
+            )
+            .expect("Has a content-addressed root")
+            .build();
+
+        assert_eq!(
+            domain.logo(),
+            domain.resource(&logo_name()).map(ContentLink::path)
+        );
+        assert!(domain.logo().is_some());
+    }
 }
@@ -29,9 +29,323 @@ pub type ValueName = String;
 /// Context, composed of (name, value) pairs.
 pub type Context = btree_map::BTreeMap<ValueName, Value>;
 
+/// A path of segments addressing a value nested inside `Value::Map`s,
+/// resolved left-to-right, e.g. `["account", "metadata", "limit"]` reads
+/// as the dotted name `account.metadata.limit`.
+///
+/// Assumes a companion `Value::Map(Context)` variant, the same way
+/// [`NumericValue`] assumes `Value::U32`/`Value::U128`/`Value::Fixed`.
+pub type QualifiedName = Vec<ValueName>;
+
+/// Reads the value at `path` inside `context`, descending through
+/// `Value::Map`s. The first missing segment, or a non-map value at an
+/// intermediate segment, yields `None`.
+pub fn get_entry<'context>(context: &'context Context, path: &[ValueName]) -> Option<&'context Value> {
+    let (first, rest) = path.split_first()?;
+    let mut value = context.get(first)?;
+    for segment in rest {
+        value = match value {
+            Value::Map(map) => map.get(segment)?,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+/// Writes `new_value` at `path` inside `context`, lazily creating
+/// missing intermediary `Value::Map`s along the way. A no-op if `path`
+/// is empty.
+pub fn set_entry(context: &mut Context, path: &[ValueName], new_value: Value) {
+    let Some((last, init)) = path.split_last() else {
+        return;
+    };
+    if init.is_empty() {
+        context.insert(last.clone(), new_value);
+        return;
+    }
+    if let Some(Value::Map(map)) = search_entry(context, init, true) {
+        map.insert(last.clone(), new_value);
+    }
+}
+
+/// Walks `path` inside `context`, returning a mutable reference to the
+/// [`Value`] at its last segment. When `create_missing` is `true` (as
+/// [`set_entry`] does), an absent segment is inserted as a fresh empty
+/// `Value::Map` instead of failing the walk; a non-map value found at an
+/// intermediate segment still fails the walk either way. `path` being
+/// empty always yields `None`.
+fn search_entry<'context>(
+    context: &'context mut Context,
+    path: &[ValueName],
+    create_missing: bool,
+) -> Option<&'context mut Value> {
+    let (first, rest) = path.split_first()?;
+    let mut value = if create_missing {
+        context
+            .entry(first.clone())
+            .or_insert_with(|| Value::Map(Context::new()))
+    } else {
+        context.get_mut(first)?
+    };
+    for segment in rest {
+        value = match value {
+            Value::Map(map) if create_missing => map
+                .entry(segment.clone())
+                .or_insert_with(|| Value::Map(Context::new())),
+            Value::Map(map) => map.get_mut(segment)?,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+/// Fully-qualified, dot-joined paths reachable in `context`, descending
+/// into every `Value::Map`.
+pub fn flatten_keys(context: &Context) -> Vec<String> {
+    fn walk(prefix: &str, context: &Context, keys: &mut Vec<String>) {
+        for (name, value) in context {
+            let path = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{prefix}.{name}")
+            };
+            match value {
+                Value::Map(nested) => walk(&path, nested, keys),
+                _ => keys.push(path),
+            }
+        }
+    }
+
+    let mut keys = Vec::new();
+    walk("", context, &mut keys);
+    keys
+}
+
 /// Boxed expression.
 pub type ExpressionBox = Box<Expression>;
 
+/// A numeric value of one of the widths arithmetic/ordering expressions
+/// operate on, so asset math isn't artificially narrowed to `u32`.
+///
+/// Assumes a companion `Value::U32`/`Value::U128`/`Value::Fixed` growing
+/// to carry these through [`TryFrom<Value>`]/[`From<NumericValue>`] for
+/// `Value`, the same way every other `EvaluatesTo`-compatible type does.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    PartialOrd, Ord,
+)]
+pub enum NumericValue {
+    /// 32-bit unsigned integer.
+    U32(u32),
+    /// 128-bit unsigned integer; the width most asset quantities need.
+    U128(u128),
+    /// Fixed-point/rational value: `mantissa / 10^scale`.
+    Fixed(Fixed),
+}
+
+/// Fixed-point/rational numeric value: `mantissa / 10^scale`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema,
+    PartialOrd, Ord,
+)]
+pub struct Fixed {
+    /// Numerator, already scaled by `10^scale`.
+    pub mantissa: u128,
+    /// Number of decimal digits `mantissa` is scaled by.
+    pub scale: u32,
+}
+
+impl Fixed {
+    /// Constructs a `Fixed` from its `mantissa` and `scale`.
+    pub const fn new(mantissa: u128, scale: u32) -> Self {
+        Self { mantissa, scale }
+    }
+
+    /// Rescales `self` to `scale`, which must be `>= self.scale` —
+    /// narrowing would silently lose precision, so it's rejected instead.
+    fn rescaled(self, scale: u32) -> Result<u128, NumericError> {
+        if scale < self.scale {
+            return Err(NumericError::Incompatible);
+        }
+        10u128
+            .checked_pow(scale - self.scale)
+            .and_then(|factor| self.mantissa.checked_mul(factor))
+            .ok_or(NumericError::Overflow)
+    }
+}
+
+/// Error produced evaluating [`NumericValue`] arithmetic or comparisons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum NumericError {
+    /// The operands' numeric kinds can't be combined without an explicit
+    /// common scale, e.g. mixing an integer with a [`Fixed`].
+    #[display(fmt = "Cannot combine these numeric operands without an explicit scale")]
+    Incompatible,
+    /// The operation's result doesn't fit in its numeric width.
+    #[display(fmt = "Numeric operation overflowed")]
+    Overflow,
+    /// Division or modulus by zero.
+    #[display(fmt = "Division or modulus by zero")]
+    DivisionByZero,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NumericError {}
+
+impl NumericValue {
+    /// Widens `self` and `other` to a common representation: the wider of
+    /// the two integer widths, or [`NumericError::Incompatible`] if mixing
+    /// a [`Fixed`] with an integer.
+    fn promote(self, other: Self) -> Result<(Self, Self), NumericError> {
+        use NumericValue::{Fixed as F, U128, U32};
+
+        Ok(match (self, other) {
+            (U32(_), U32(_)) | (U128(_), U128(_)) | (F(_), F(_)) => (self, other),
+            (U32(lhs), U128(rhs)) => (U128(u128::from(lhs)), U128(rhs)),
+            (U128(lhs), U32(rhs)) => (U128(lhs), U128(u128::from(rhs))),
+            (F(_), U32(_) | U128(_)) | (U32(_) | U128(_), F(_)) => {
+                return Err(NumericError::Incompatible)
+            }
+        })
+    }
+
+    /// Checked addition: widens per [`Self::promote`], then adds in the
+    /// common width, erroring on overflow instead of wrapping.
+    pub fn checked_add(self, other: Self) -> Result<Self, NumericError> {
+        match self.promote(other)? {
+            (Self::U32(lhs), Self::U32(rhs)) => {
+                lhs.checked_add(rhs).map(Self::U32).ok_or(NumericError::Overflow)
+            }
+            (Self::U128(lhs), Self::U128(rhs)) => {
+                lhs.checked_add(rhs).map(Self::U128).ok_or(NumericError::Overflow)
+            }
+            (Self::Fixed(lhs), Self::Fixed(rhs)) => {
+                let scale = lhs.scale.max(rhs.scale);
+                let lhs = lhs.rescaled(scale)?;
+                let rhs = rhs.rescaled(scale)?;
+                lhs.checked_add(rhs)
+                    .map(|mantissa| Self::Fixed(Fixed::new(mantissa, scale)))
+                    .ok_or(NumericError::Overflow)
+            }
+            _ => unreachable!("`promote` only returns same-kind pairs"),
+        }
+    }
+
+    /// Checked subtraction: see [`Self::checked_add`].
+    pub fn checked_sub(self, other: Self) -> Result<Self, NumericError> {
+        match self.promote(other)? {
+            (Self::U32(lhs), Self::U32(rhs)) => {
+                lhs.checked_sub(rhs).map(Self::U32).ok_or(NumericError::Overflow)
+            }
+            (Self::U128(lhs), Self::U128(rhs)) => {
+                lhs.checked_sub(rhs).map(Self::U128).ok_or(NumericError::Overflow)
+            }
+            (Self::Fixed(lhs), Self::Fixed(rhs)) => {
+                let scale = lhs.scale.max(rhs.scale);
+                let lhs = lhs.rescaled(scale)?;
+                let rhs = rhs.rescaled(scale)?;
+                lhs.checked_sub(rhs)
+                    .map(|mantissa| Self::Fixed(Fixed::new(mantissa, scale)))
+                    .ok_or(NumericError::Overflow)
+            }
+            _ => unreachable!("`promote` only returns same-kind pairs"),
+        }
+    }
+
+    /// Checked multiplication: see [`Self::checked_add`]. `Fixed *
+    /// Fixed` keeps the wider operand's scale, dividing out the other
+    /// factor of `10^scale` the raw mantissa product picks up.
+    pub fn checked_mul(self, other: Self) -> Result<Self, NumericError> {
+        match self.promote(other)? {
+            (Self::U32(lhs), Self::U32(rhs)) => {
+                lhs.checked_mul(rhs).map(Self::U32).ok_or(NumericError::Overflow)
+            }
+            (Self::U128(lhs), Self::U128(rhs)) => {
+                lhs.checked_mul(rhs).map(Self::U128).ok_or(NumericError::Overflow)
+            }
+            (Self::Fixed(lhs), Self::Fixed(rhs)) => {
+                let scale = lhs.scale.max(rhs.scale);
+                let lhs = lhs.rescaled(scale)?;
+                let rhs = rhs.rescaled(scale)?;
+                let divisor = 10u128.checked_pow(scale).ok_or(NumericError::Overflow)?;
+                lhs.checked_mul(rhs)
+                    .and_then(|product| product.checked_div(divisor))
+                    .map(|mantissa| Self::Fixed(Fixed::new(mantissa, scale)))
+                    .ok_or(NumericError::Overflow)
+            }
+            _ => unreachable!("`promote` only returns same-kind pairs"),
+        }
+    }
+
+    /// Checked division: see [`Self::checked_add`]. `Fixed / Fixed`
+    /// keeps the wider operand's scale of precision in the quotient.
+    pub fn checked_div(self, other: Self) -> Result<Self, NumericError> {
+        match self.promote(other)? {
+            (Self::U32(lhs), Self::U32(rhs)) => {
+                (rhs != 0).then(|| lhs / rhs).map(Self::U32).ok_or(NumericError::DivisionByZero)
+            }
+            (Self::U128(lhs), Self::U128(rhs)) => (rhs != 0)
+                .then(|| lhs / rhs)
+                .map(Self::U128)
+                .ok_or(NumericError::DivisionByZero),
+            (Self::Fixed(lhs), Self::Fixed(rhs)) => {
+                let scale = lhs.scale.max(rhs.scale);
+                let lhs = lhs.rescaled(scale)?;
+                let rhs = rhs.rescaled(scale)?;
+                if rhs == 0 {
+                    return Err(NumericError::DivisionByZero);
+                }
+                let factor = 10u128.checked_pow(scale).ok_or(NumericError::Overflow)?;
+                let numerator = lhs.checked_mul(factor).ok_or(NumericError::Overflow)?;
+                Ok(Self::Fixed(Fixed::new(numerator / rhs, scale)))
+            }
+            _ => unreachable!("`promote` only returns same-kind pairs"),
+        }
+    }
+
+    /// Checked modulus: see [`Self::checked_div`].
+    pub fn checked_rem(self, other: Self) -> Result<Self, NumericError> {
+        match self.promote(other)? {
+            (Self::U32(lhs), Self::U32(rhs)) => {
+                (rhs != 0).then(|| lhs % rhs).map(Self::U32).ok_or(NumericError::DivisionByZero)
+            }
+            (Self::U128(lhs), Self::U128(rhs)) => (rhs != 0)
+                .then(|| lhs % rhs)
+                .map(Self::U128)
+                .ok_or(NumericError::DivisionByZero),
+            (Self::Fixed(lhs), Self::Fixed(rhs)) => {
+                let scale = lhs.scale.max(rhs.scale);
+                let lhs = lhs.rescaled(scale)?;
+                let rhs = rhs.rescaled(scale)?;
+                if rhs == 0 {
+                    return Err(NumericError::DivisionByZero);
+                }
+                Ok(Self::Fixed(Fixed::new(lhs % rhs, scale)))
+            }
+            _ => unreachable!("`promote` only returns same-kind pairs"),
+        }
+    }
+
+    /// Checked exponentiation. The exponent must be an integer that fits
+    /// in a `u32`; raising a [`Fixed`] to a power isn't supported.
+    pub fn checked_pow(self, exponent: Self) -> Result<Self, NumericError> {
+        let exponent = match exponent {
+            Self::U32(exponent) => exponent,
+            Self::U128(exponent) => {
+                u32::try_from(exponent).map_err(|_| NumericError::Overflow)?
+            }
+            Self::Fixed(_) => return Err(NumericError::Incompatible),
+        };
+        match self {
+            Self::U32(base) => base.checked_pow(exponent).map(Self::U32).ok_or(NumericError::Overflow),
+            Self::U128(base) => {
+                base.checked_pow(exponent).map(Self::U128).ok_or(NumericError::Overflow)
+            }
+            Self::Fixed(_) => Err(NumericError::Incompatible),
+        }
+    }
+}
+
 /// Struct for type checking and converting expression results.
 #[derive(
     Debug, Display, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, PartialOrd, Ord,
@@ -83,6 +397,29 @@ impl<V: IntoSchema + TryFrom<Value>> IntoSchema for EvaluatesTo<V> {
     }
 }
 
+impl TryFrom<Value> for NumericValue {
+    type Error = iroha_macro::error::ErrorTryFromEnum<Value, Self>;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::U32(value) => Ok(Self::U32(value)),
+            Value::U128(value) => Ok(Self::U128(value)),
+            Value::Fixed(value) => Ok(Self::Fixed(value)),
+            _ => Err(Self::Error::default()),
+        }
+    }
+}
+
+impl From<NumericValue> for Value {
+    fn from(value: NumericValue) -> Self {
+        match value {
+            NumericValue::U32(value) => Self::U32(value),
+            NumericValue::U128(value) => Self::U128(value),
+            NumericValue::Fixed(value) => Self::Fixed(value),
+        }
+    }
+}
+
 /// Represents all possible expressions.
 #[derive(
     Debug,
@@ -139,6 +476,197 @@ pub enum Expression {
     Where(Where),
     /// Get a temporary value by name
     ContextValue(ContextValue),
+    /// Multi-arm pattern match, replacing a hand-nested `If` chain.
+    Match(Match),
+    /// Call to a named builtin or user-registered function.
+    Call(Call),
+    /// Sum of a (optionally bucketed) collection.
+    Sum(Sum),
+    /// Count of elements in a (optionally bucketed) collection.
+    Count(Count),
+    /// Minimum of a (optionally bucketed) collection.
+    Min(Min),
+    /// Maximum of a (optionally bucketed) collection.
+    Max(Max),
+    /// Average of a (optionally bucketed) collection.
+    Average(Average),
+    /// Union of two context-producing sub-expressions into one binding map.
+    ContextMerge(ContextMerge),
+}
+
+/// Replaces the [`ExpressionBox`] wrapped by `value` with the result of
+/// `f`, reused by every [`Expression::map_children`] arm.
+fn over<V: TryFrom<Value>>(
+    mut value: EvaluatesTo<V>,
+    f: &mut impl FnMut(ExpressionBox) -> ExpressionBox,
+) -> EvaluatesTo<V> {
+    value.expression = f(value.expression);
+    value
+}
+
+/// Applies `f` to every [`ExpressionBox`] embedded in a [`Pattern`].
+fn map_pattern(pattern: Pattern, f: &mut impl FnMut(ExpressionBox) -> ExpressionBox) -> Pattern {
+    match pattern {
+        Pattern::Exact(value) => Pattern::Exact(over(value, f)),
+        Pattern::Range { lo, hi } => Pattern::Range {
+            lo: over(lo, f),
+            hi: over(hi, f),
+        },
+        Pattern::In(elements) => Pattern::In(over(elements, f)),
+    }
+}
+
+/// Applies `f` to an [`Aggregate`]'s `input` and every `group_by` key
+/// expression, reused by every `Sum`/`Count`/`Min`/`Max`/`Average` arm of
+/// [`Expression::map_children`].
+fn map_aggregate(mut aggregate: Aggregate, f: &mut impl FnMut(ExpressionBox) -> ExpressionBox) -> Aggregate {
+    aggregate.input = over(aggregate.input, f);
+    aggregate.group_by = aggregate.group_by.map(|mut group_by| {
+        group_by.key_expressions = group_by.key_expressions.into_iter().map(&mut *f).collect();
+        group_by
+    });
+    aggregate
+}
+
+/// Extracts the two operands of a numeric comparison/arithmetic node as
+/// [`NumericValue`]s, if both are already [`Expression::Raw`] literals.
+fn numeric_pair(
+    left: &EvaluatesTo<NumericValue>,
+    right: &EvaluatesTo<NumericValue>,
+) -> Option<(NumericValue, NumericValue)> {
+    match (left.expression.as_raw(), right.expression.as_raw()) {
+        (Some(left), Some(right)) => NumericValue::try_from(left.clone())
+            .ok()
+            .zip(NumericValue::try_from(right.clone()).ok()),
+        _ => None,
+    }
+}
+
+/// Folds a numeric binary node: if both operands are raw numeric literals
+/// and `op` succeeds, replaces the node with the single resulting
+/// [`Expression::Raw`]; otherwise rebuilds the original node via `rebuild`
+/// so a dynamic operand, or an operation `op` can't complete (e.g.
+/// division by zero), never gets silently dropped.
+fn fold_numeric<S: Into<ExpressionBox>>(
+    left: EvaluatesTo<NumericValue>,
+    right: EvaluatesTo<NumericValue>,
+    op: impl FnOnce(NumericValue, NumericValue) -> Result<NumericValue, NumericError>,
+    rebuild: impl FnOnce(EvaluatesTo<NumericValue>, EvaluatesTo<NumericValue>) -> S,
+) -> ExpressionBox {
+    if let Some((left_value, right_value)) = numeric_pair(&left, &right) {
+        if let Ok(result) = op(left_value, right_value) {
+            return Box::new(Expression::Raw(Box::new(result.into())));
+        }
+    }
+    rebuild(left, right).into()
+}
+
+/// Declared [`ValueKind`] of every currently-bound [`ContextValue`] name,
+/// threaded through an [`Expression::validate_types`] walk and extended
+/// by each [`Where`] it descends into.
+pub type TypeEnvironment = btree_map::BTreeMap<ValueName, ValueKind>;
+
+/// A static type mismatch found by [`Expression::validate_types`], naming
+/// the offending sub-expression and its expected-vs-found [`ValueKind`]s.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display(fmt = "{context}: expected {expected:?}, found {found:?}")]
+pub struct TypeError {
+    /// Kind the node required.
+    pub expected: ValueKind,
+    /// Kind the operand actually resolved to.
+    pub found: ValueKind,
+    /// Name of the node/operand that mismatched, e.g. `"If::condition"`.
+    pub context: String,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TypeError {}
+
+/// Checks `actual` against `expected`, succeeding vacuously if either is
+/// [`ValueKind::Unknown`] (undetermined without evaluation).
+fn expect_kind(actual: ValueKind, expected: ValueKind, context: &str) -> Result<(), TypeError> {
+    if actual == ValueKind::Unknown || actual == expected {
+        Ok(())
+    } else {
+        Err(TypeError {
+            expected,
+            found: actual,
+            context: context.to_owned(),
+        })
+    }
+}
+
+/// Unifies two branches' (e.g. `If`'s then/else) [`ValueKind`]s:
+/// [`ValueKind::Unknown`] defers to the other side, otherwise both sides
+/// must already agree.
+fn unify(left: ValueKind, right: ValueKind, context: &str) -> Result<ValueKind, TypeError> {
+    match (left, right) {
+        (ValueKind::Unknown, other) | (other, ValueKind::Unknown) => Ok(other),
+        (left, right) if left == right => Ok(left),
+        (left, right) => Err(TypeError {
+            expected: left,
+            found: right,
+            context: context.to_owned(),
+        }),
+    }
+}
+
+/// Best-effort [`ValueKind`] of an already-evaluated [`Value`] literal.
+fn value_kind(value: &Value) -> ValueKind {
+    match value {
+        Value::U32(_) | Value::U128(_) | Value::Fixed(_) => ValueKind::Numeric,
+        Value::Bool(_) => ValueKind::Bool,
+        Value::String(_) => ValueKind::String,
+        Value::Vec(_) => ValueKind::Vec,
+        Value::Map(_) => ValueKind::Map,
+        _ => ValueKind::Unknown,
+    }
+}
+
+/// Checks a [`Pattern`]'s embedded expressions.
+fn validate_pattern(pattern: &Pattern, types: &TypeEnvironment) -> Result<(), TypeError> {
+    match pattern {
+        Pattern::Exact(value) => {
+            value.expression.validate_types(types)?;
+            Ok(())
+        }
+        Pattern::Range { lo, hi } => {
+            expect_kind(
+                lo.expression.validate_types(types)?,
+                ValueKind::Numeric,
+                "Pattern::Range::lo",
+            )?;
+            expect_kind(
+                hi.expression.validate_types(types)?,
+                ValueKind::Numeric,
+                "Pattern::Range::hi",
+            )?;
+            Ok(())
+        }
+        Pattern::In(elements) => {
+            expect_kind(
+                elements.expression.validate_types(types)?,
+                ValueKind::Vec,
+                "Pattern::In",
+            )?;
+            Ok(())
+        }
+    }
+}
+
+/// Checks an [`Aggregate`]'s `input` and `group_by` key expressions.
+fn validate_aggregate(aggregate: &Aggregate, types: &TypeEnvironment) -> Result<(), TypeError> {
+    expect_kind(
+        aggregate.input.expression.validate_types(types)?,
+        ValueKind::Vec,
+        "Aggregate::input",
+    )?;
+    if let Some(group_by) = &aggregate.group_by {
+        for key in &group_by.key_expressions {
+            key.validate_types(types)?;
+        }
+    }
+    Ok(())
 }
 
 impl Expression {
@@ -168,6 +696,457 @@ impl Expression {
             Divide(divide) => divide.len(),
             Mod(modulus) => modulus.len(),
             RaiseTo(raise_to) => raise_to.len(),
+            Match(match_expression) => match_expression.len(),
+            Call(call) => call.len(),
+            Sum(sum) => sum.len(),
+            Count(count) => count.len(),
+            Min(min) => min.len(),
+            Max(max) => max.len(),
+            Average(average) => average.len(),
+            ContextMerge(context_merge) => context_merge.len(),
+        }
+    }
+
+    /// Best-effort static type of `self` under `types`, without failing:
+    /// a node that doesn't type-check reports [`ValueKind::Unknown`]
+    /// rather than propagating the [`TypeError`]. See [`Self::validate_types`]
+    /// for the failing pre-flight check.
+    #[must_use]
+    pub fn return_type(&self, types: &TypeEnvironment) -> ValueKind {
+        self.validate_types(types).unwrap_or(ValueKind::Unknown)
+    }
+
+    /// Recursively checks that every operand of `self` has the
+    /// [`ValueKind`] its node requires, resolving [`ContextValue`] names
+    /// against `types` (augmented by any [`Where`] `self` descends
+    /// into), and returns `self`'s resulting kind if it does. Rejects an
+    /// ill-typed tree before it is ever evaluated on-chain.
+    ///
+    /// # Errors
+    /// Fails with the first ill-typed sub-expression found, naming it
+    /// and its expected-vs-found kinds.
+    pub fn validate_types(&self, types: &TypeEnvironment) -> Result<ValueKind, TypeError> {
+        use Expression::*;
+
+        match self {
+            Add(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Add::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Add::right")?;
+                Ok(ValueKind::Numeric)
+            }
+            Subtract(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Subtract::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Subtract::right")?;
+                Ok(ValueKind::Numeric)
+            }
+            Multiply(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Multiply::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Multiply::right")?;
+                Ok(ValueKind::Numeric)
+            }
+            Divide(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Divide::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Divide::right")?;
+                Ok(ValueKind::Numeric)
+            }
+            Mod(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Mod::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Mod::right")?;
+                Ok(ValueKind::Numeric)
+            }
+            RaiseTo(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "RaiseTo::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "RaiseTo::right")?;
+                Ok(ValueKind::Numeric)
+            }
+            Greater(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Greater::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Greater::right")?;
+                Ok(ValueKind::Bool)
+            }
+            Less(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Numeric, "Less::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Numeric, "Less::right")?;
+                Ok(ValueKind::Bool)
+            }
+            Equal(e) => {
+                let left = e.left.expression.validate_types(types)?;
+                let right = e.right.expression.validate_types(types)?;
+                unify(left, right, "Equal")?;
+                Ok(ValueKind::Bool)
+            }
+            Not(e) => {
+                expect_kind(e.expression.expression.validate_types(types)?, ValueKind::Bool, "Not::expression")?;
+                Ok(ValueKind::Bool)
+            }
+            And(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Bool, "And::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Bool, "And::right")?;
+                Ok(ValueKind::Bool)
+            }
+            Or(e) => {
+                expect_kind(e.left.expression.validate_types(types)?, ValueKind::Bool, "Or::left")?;
+                expect_kind(e.right.expression.validate_types(types)?, ValueKind::Bool, "Or::right")?;
+                Ok(ValueKind::Bool)
+            }
+            If(e) => {
+                expect_kind(e.condition.expression.validate_types(types)?, ValueKind::Bool, "If::condition")?;
+                let then_type = e.then_expression.expression.validate_types(types)?;
+                let else_type = e.else_expression.expression.validate_types(types)?;
+                unify(then_type, else_type, "If")
+            }
+            Raw(raw) => Ok(value_kind(raw)),
+            Query(_query) => Ok(ValueKind::Unknown),
+            Contains(e) => {
+                expect_kind(
+                    e.collection.expression.validate_types(types)?,
+                    ValueKind::Vec,
+                    "Contains::collection",
+                )?;
+                e.element.expression.validate_types(types)?;
+                Ok(ValueKind::Bool)
+            }
+            ContainsAll(e) => {
+                expect_kind(
+                    e.collection.expression.validate_types(types)?,
+                    ValueKind::Vec,
+                    "ContainsAll::collection",
+                )?;
+                expect_kind(
+                    e.elements.expression.validate_types(types)?,
+                    ValueKind::Vec,
+                    "ContainsAll::elements",
+                )?;
+                Ok(ValueKind::Bool)
+            }
+            ContainsAny(e) => {
+                expect_kind(
+                    e.collection.expression.validate_types(types)?,
+                    ValueKind::Vec,
+                    "ContainsAny::collection",
+                )?;
+                expect_kind(
+                    e.elements.expression.validate_types(types)?,
+                    ValueKind::Vec,
+                    "ContainsAny::elements",
+                )?;
+                Ok(ValueKind::Bool)
+            }
+            Where(e) => {
+                let mut scoped = types.clone();
+                for (name, value) in &e.values {
+                    let value_type = value.expression.validate_types(types)?;
+                    scoped.insert(name.clone(), value_type);
+                }
+                for (name, value) in &e.sequential_values {
+                    let value_type = value.expression.validate_types(&scoped)?;
+                    scoped.insert(name.clone(), value_type);
+                }
+                e.expression.expression.validate_types(&scoped)
+            }
+            ContextValue(context_value) => Ok(types
+                .get(&context_value.value_name)
+                .copied()
+                .unwrap_or(ValueKind::Unknown)),
+            Match(e) => {
+                e.scrutinee.expression.validate_types(types)?;
+                let mut result = e.default.expression.validate_types(types)?;
+                for (pattern, body) in &e.arms {
+                    validate_pattern(pattern, types)?;
+                    let body_type = body.expression.validate_types(types)?;
+                    result = unify(result, body_type, "Match arm")?;
+                }
+                Ok(result)
+            }
+            Call(call) => {
+                for arg in &call.args {
+                    arg.expression.validate_types(types)?;
+                }
+                Ok(ValueKind::Unknown)
+            }
+            Sum(e) => validate_aggregate(&e.aggregate, types).map(|()| ValueKind::Numeric),
+            Count(e) => validate_aggregate(&e.aggregate, types).map(|()| ValueKind::Numeric),
+            Min(e) => validate_aggregate(&e.aggregate, types).map(|()| ValueKind::Numeric),
+            Max(e) => validate_aggregate(&e.aggregate, types).map(|()| ValueKind::Numeric),
+            Average(e) => validate_aggregate(&e.aggregate, types).map(|()| ValueKind::Numeric),
+            ContextMerge(e) => {
+                expect_kind(
+                    e.left.expression.validate_types(types)?,
+                    ValueKind::Map,
+                    "ContextMerge::left",
+                )?;
+                expect_kind(
+                    e.right.expression.validate_types(types)?,
+                    ValueKind::Map,
+                    "ContextMerge::right",
+                )?;
+                Ok(ValueKind::Map)
+            }
+        }
+    }
+
+    /// `Some(value)` if `self` is already an [`Expression::Raw`] literal.
+    fn as_raw(&self) -> Option<&Value> {
+        match self {
+            Self::Raw(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Applies `f` to every direct child [`ExpressionBox`], rebuilding the
+    /// same variant with the results. Factors out the traversal so other
+    /// passes besides [`Self::fold`] can walk the tree without repeating
+    /// the match over every variant.
+    pub fn map_children(self, mut f: impl FnMut(ExpressionBox) -> ExpressionBox) -> Self {
+        use Expression::*;
+
+        match self {
+            Add(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Add(e)
+            }
+            Subtract(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Subtract(e)
+            }
+            Multiply(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Multiply(e)
+            }
+            Divide(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Divide(e)
+            }
+            Mod(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Mod(e)
+            }
+            RaiseTo(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                RaiseTo(e)
+            }
+            Greater(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Greater(e)
+            }
+            Less(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Less(e)
+            }
+            Equal(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Equal(e)
+            }
+            Not(mut e) => {
+                e.expression = over(e.expression, &mut f);
+                Not(e)
+            }
+            And(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                And(e)
+            }
+            Or(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                Or(e)
+            }
+            If(mut e) => {
+                e.condition = over(e.condition, &mut f);
+                e.then_expression = over(e.then_expression, &mut f);
+                e.else_expression = over(e.else_expression, &mut f);
+                If(e)
+            }
+            Raw(raw) => Raw(raw),
+            Query(query) => Query(query),
+            Contains(mut e) => {
+                e.collection = over(e.collection, &mut f);
+                e.element = over(e.element, &mut f);
+                Contains(e)
+            }
+            ContainsAll(mut e) => {
+                e.collection = over(e.collection, &mut f);
+                e.elements = over(e.elements, &mut f);
+                ContainsAll(e)
+            }
+            ContainsAny(mut e) => {
+                e.collection = over(e.collection, &mut f);
+                e.elements = over(e.elements, &mut f);
+                ContainsAny(e)
+            }
+            Where(mut e) => {
+                e.expression = over(e.expression, &mut f);
+                e.values = e
+                    .values
+                    .into_iter()
+                    .map(|(name, value)| (name, over(value, &mut f)))
+                    .collect();
+                e.sequential_values = e
+                    .sequential_values
+                    .into_iter()
+                    .map(|(name, value)| (name, over(value, &mut f)))
+                    .collect();
+                Where(e)
+            }
+            ContextValue(value) => ContextValue(value),
+            Match(mut e) => {
+                e.scrutinee = over(e.scrutinee, &mut f);
+                e.arms = e
+                    .arms
+                    .into_iter()
+                    .map(|(pattern, body)| (map_pattern(pattern, &mut f), over(body, &mut f)))
+                    .collect();
+                e.default = over(e.default, &mut f);
+                Match(e)
+            }
+            Call(mut e) => {
+                e.args = e.args.into_iter().map(|arg| over(arg, &mut f)).collect();
+                Call(e)
+            }
+            Sum(mut e) => {
+                e.aggregate = map_aggregate(e.aggregate, &mut f);
+                Sum(e)
+            }
+            Count(mut e) => {
+                e.aggregate = map_aggregate(e.aggregate, &mut f);
+                Count(e)
+            }
+            Min(mut e) => {
+                e.aggregate = map_aggregate(e.aggregate, &mut f);
+                Min(e)
+            }
+            Max(mut e) => {
+                e.aggregate = map_aggregate(e.aggregate, &mut f);
+                Max(e)
+            }
+            Average(mut e) => {
+                e.aggregate = map_aggregate(e.aggregate, &mut f);
+                Average(e)
+            }
+            ContextMerge(mut e) => {
+                e.left = over(e.left, &mut f);
+                e.right = over(e.right, &mut f);
+                ContextMerge(e)
+            }
+        }
+    }
+
+    /// Bottom-up constant-folding / simplification pass. Recurses into
+    /// every child first, then, if every child of an arithmetic, boolean,
+    /// or comparison node turned out to be an [`Expression::Raw`] literal,
+    /// evaluates the node and replaces it with a single `Raw`. A few
+    /// algebraic shortcuts apply without every child being constant:
+    /// `And`/`Or` short-circuit on a literal `false`/`true` child, `If`
+    /// collapses to its taken branch on a literal condition, and
+    /// `Not(Not(x))` cancels down to `x`. Subtrees that depend on an
+    /// [`Expression::Query`], an [`Expression::ContextValue`], or a name
+    /// bound by [`Expression::Where`] never become all-`Raw`, so they're
+    /// left dynamic and folding never changes observed semantics.
+    #[must_use]
+    pub fn fold(self) -> ExpressionBox {
+        self.map_children(|child| Box::new((*child).fold()))
+            .simplify()
+    }
+
+    /// Single-node simplification step, assuming children are already
+    /// folded. See [`Self::fold`].
+    fn simplify(self) -> ExpressionBox {
+        use Expression::*;
+
+        match self {
+            Add(add) => fold_numeric(add.left, add.right, NumericValue::checked_add, Add::new),
+            Subtract(subtract) => fold_numeric(
+                subtract.left,
+                subtract.right,
+                NumericValue::checked_sub,
+                Subtract::new,
+            ),
+            Multiply(multiply) => fold_numeric(
+                multiply.left,
+                multiply.right,
+                NumericValue::checked_mul,
+                Multiply::new,
+            ),
+            Divide(divide) => fold_numeric(
+                divide.left,
+                divide.right,
+                NumericValue::checked_div,
+                Divide::new,
+            ),
+            Mod(modulus) => fold_numeric(
+                modulus.left,
+                modulus.right,
+                NumericValue::checked_rem,
+                Mod::new,
+            ),
+            RaiseTo(raise_to) => fold_numeric(
+                raise_to.left,
+                raise_to.right,
+                NumericValue::checked_pow,
+                RaiseTo::new,
+            ),
+            Greater(greater) => match numeric_pair(&greater.left, &greater.right) {
+                Some((left, right)) => Box::new(Raw(Box::new(Value::Bool(left > right)))),
+                None => Box::new(Greater(greater)),
+            },
+            Less(less) => match numeric_pair(&less.left, &less.right) {
+                Some((left, right)) => Box::new(Raw(Box::new(Value::Bool(left < right)))),
+                None => Box::new(Less(less)),
+            },
+            Equal(equal) => match (equal.left.expression.as_raw(), equal.right.expression.as_raw()) {
+                (Some(left), Some(right)) => Box::new(Raw(Box::new(Value::Bool(left == right)))),
+                _ => Box::new(Equal(equal)),
+            },
+            Not(not) => {
+                if let Not(inner) = *not.expression.expression {
+                    return inner.expression.expression;
+                }
+                match not.expression.expression.as_raw() {
+                    Some(Value::Bool(value)) => Box::new(Raw(Box::new(Value::Bool(!*value)))),
+                    _ => Box::new(Not(not)),
+                }
+            }
+            And(and) => {
+                if matches!(and.left.expression.as_raw(), Some(Value::Bool(false)))
+                    || matches!(and.right.expression.as_raw(), Some(Value::Bool(false)))
+                {
+                    return Box::new(Raw(Box::new(Value::Bool(false))));
+                }
+                match (and.left.expression.as_raw(), and.right.expression.as_raw()) {
+                    (Some(Value::Bool(left)), Some(Value::Bool(right))) => {
+                        Box::new(Raw(Box::new(Value::Bool(*left && *right))))
+                    }
+                    _ => Box::new(And(and)),
+                }
+            }
+            Or(or) => {
+                if matches!(or.left.expression.as_raw(), Some(Value::Bool(true)))
+                    || matches!(or.right.expression.as_raw(), Some(Value::Bool(true)))
+                {
+                    return Box::new(Raw(Box::new(Value::Bool(true))));
+                }
+                match (or.left.expression.as_raw(), or.right.expression.as_raw()) {
+                    (Some(Value::Bool(left)), Some(Value::Bool(right))) => {
+                        Box::new(Raw(Box::new(Value::Bool(*left || *right))))
+                    }
+                    _ => Box::new(Or(or)),
+                }
+            }
+            If(if_expression) => match if_expression.condition.expression.as_raw() {
+                Some(Value::Bool(true)) => if_expression.then_expression.expression,
+                Some(Value::Bool(false)) => if_expression.else_expression.expression,
+                _ => Box::new(If(if_expression)),
+            },
+            other => Box::new(other),
         }
     }
 }
@@ -184,7 +1163,9 @@ impl<T: Into<Value>> From<T> for ExpressionBox {
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct ContextValue {
-    /// Name bound to the value.
+    /// Name bound to the value. May be a qualified, dot-joined path (e.g.
+    /// `account.metadata.limit`) addressing a value nested inside
+    /// `Value::Map`s; see [`Self::segments`].
     pub value_name: String,
 }
 
@@ -200,6 +1181,13 @@ impl ContextValue {
             value_name: String::from(value_name),
         }
     }
+
+    /// Splits `self.value_name` into the path [`get_entry`]/[`set_entry`]
+    /// resolve left-to-right, e.g. `"account.metadata.limit"` splits into
+    /// `["account", "metadata", "limit"]`.
+    pub fn segments(&self) -> QualifiedName {
+        self.value_name.split('.').map(ValueName::from).collect()
+    }
 }
 
 impl From<ContextValue> for ExpressionBox {
@@ -209,15 +1197,15 @@ impl From<ContextValue> for ExpressionBox {
 }
 
 /// Evaluates to the multiplication of right and left expressions.
-/// Works only for `Value::U32`
+/// Operates on any [`NumericValue`] width
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Multiply {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Multiply {
@@ -227,7 +1215,7 @@ impl Multiply {
     }
 
     /// Constructs `Multiply` expression.
-    pub fn new(left: impl Into<EvaluatesTo<u32>>, right: impl Into<EvaluatesTo<u32>>) -> Self {
+    pub fn new(left: impl Into<EvaluatesTo<NumericValue>>, right: impl Into<EvaluatesTo<NumericValue>>) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -242,15 +1230,15 @@ impl From<Multiply> for ExpressionBox {
 }
 
 /// Evaluates to the division of right and left expressions.
-/// Works only for `Value::U32`
+/// Operates on any [`NumericValue`] width
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Divide {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Divide {
@@ -260,7 +1248,7 @@ impl Divide {
     }
 
     /// Constructs `Multiply` expression.
-    pub fn new(left: impl Into<EvaluatesTo<u32>>, right: impl Into<EvaluatesTo<u32>>) -> Self {
+    pub fn new(left: impl Into<EvaluatesTo<NumericValue>>, right: impl Into<EvaluatesTo<NumericValue>>) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -275,15 +1263,15 @@ impl From<Divide> for ExpressionBox {
 }
 
 /// Evaluates to the modulus of right and left expressions.
-/// Works only for `Value::U32`
+/// Operates on any [`NumericValue`] width
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Mod {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Mod {
@@ -293,7 +1281,7 @@ impl Mod {
     }
 
     /// Constructs `Mod` expression.
-    pub fn new(left: impl Into<EvaluatesTo<u32>>, right: impl Into<EvaluatesTo<u32>>) -> Self {
+    pub fn new(left: impl Into<EvaluatesTo<NumericValue>>, right: impl Into<EvaluatesTo<NumericValue>>) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -308,15 +1296,15 @@ impl From<Mod> for ExpressionBox {
 }
 
 /// Evaluates to the right expression in power of left expressions.
-/// Works only for `Value::U32`
+/// Operates on any [`NumericValue`] width
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct RaiseTo {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl RaiseTo {
@@ -326,7 +1314,7 @@ impl RaiseTo {
     }
 
     /// Constructs `RaiseTo` expression.
-    pub fn new(left: impl Into<EvaluatesTo<u32>>, right: impl Into<EvaluatesTo<u32>>) -> Self {
+    pub fn new(left: impl Into<EvaluatesTo<NumericValue>>, right: impl Into<EvaluatesTo<NumericValue>>) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -341,15 +1329,15 @@ impl From<RaiseTo> for ExpressionBox {
 }
 
 /// Evaluates to the sum of right and left expressions.
-/// Works only for `Value::U32`
+/// Operates on any [`NumericValue`] width
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Add {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Add {
@@ -359,7 +1347,7 @@ impl Add {
     }
 
     /// Constructs `Add` expression.
-    pub fn new<L: Into<EvaluatesTo<u32>>, R: Into<EvaluatesTo<u32>>>(left: L, right: R) -> Self {
+    pub fn new<L: Into<EvaluatesTo<NumericValue>>, R: Into<EvaluatesTo<NumericValue>>>(left: L, right: R) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -374,15 +1362,15 @@ impl From<Add> for ExpressionBox {
 }
 
 /// Evaluates to the difference of right and left expressions.
-/// Works only for `Value::U32`
+/// Operates on any [`NumericValue`] width
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Subtract {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Subtract {
@@ -392,7 +1380,7 @@ impl Subtract {
     }
 
     /// Constructs `Subtract` expression.
-    pub fn new<L: Into<EvaluatesTo<u32>>, R: Into<EvaluatesTo<u32>>>(left: L, right: R) -> Self {
+    pub fn new<L: Into<EvaluatesTo<NumericValue>>, R: Into<EvaluatesTo<NumericValue>>>(left: L, right: R) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -407,15 +1395,15 @@ impl From<Subtract> for ExpressionBox {
 }
 
 /// Returns whether the `left` expression is greater than the `right`.
-/// Works only for `Value::U32`.
+/// Operates on any [`NumericValue`] width.
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Greater {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Greater {
@@ -425,7 +1413,7 @@ impl Greater {
     }
 
     /// Constructs `Greater` expression.
-    pub fn new<L: Into<EvaluatesTo<u32>>, R: Into<EvaluatesTo<u32>>>(left: L, right: R) -> Self {
+    pub fn new<L: Into<EvaluatesTo<NumericValue>>, R: Into<EvaluatesTo<NumericValue>>>(left: L, right: R) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -440,15 +1428,15 @@ impl From<Greater> for ExpressionBox {
 }
 
 /// Returns whether the `left` expression is less than the `right`.
-/// Works only for `Value::U32`.
+/// Operates on any [`NumericValue`] width.
 #[derive(
     Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
 )]
 pub struct Less {
     /// Left operand.
-    pub left: EvaluatesTo<u32>,
+    pub left: EvaluatesTo<NumericValue>,
     /// Right operand.
-    pub right: EvaluatesTo<u32>,
+    pub right: EvaluatesTo<NumericValue>,
 }
 
 impl Less {
@@ -458,7 +1446,7 @@ impl Less {
     }
 
     /// Constructs `Less` expression.
-    pub fn new<L: Into<EvaluatesTo<u32>>, R: Into<EvaluatesTo<u32>>>(left: L, right: R) -> Self {
+    pub fn new<L: Into<EvaluatesTo<NumericValue>>, R: Into<EvaluatesTo<NumericValue>>>(left: L, right: R) -> Self {
         Self {
             left: left.into(),
             right: right.into(),
@@ -665,6 +1653,811 @@ impl From<If> for ExpressionBox {
     }
 }
 
+/// A pattern tried against a [`Match`] expression's scrutinee.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub enum Pattern {
+    /// Matches if the scrutinee equals `value` exactly.
+    Exact(EvaluatesTo<Value>),
+    /// Matches if the scrutinee, as a [`NumericValue`], falls within
+    /// `lo..=hi` (inclusive on both ends).
+    Range {
+        /// Inclusive lower bound.
+        lo: EvaluatesTo<NumericValue>,
+        /// Inclusive upper bound.
+        hi: EvaluatesTo<NumericValue>,
+    },
+    /// Matches if the scrutinee is contained in `elements`.
+    In(EvaluatesTo<Vec<Value>>),
+}
+
+impl Pattern {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Exact(value) => value.len(),
+            Self::Range { lo, hi } => lo.len() + hi.len(),
+            Self::In(elements) => elements.len(),
+        }
+    }
+}
+
+/// Builder for [`Match`] expression.
+#[derive(Debug)]
+#[must_use = ".build() not used"]
+pub struct MatchBuilder {
+    scrutinee: EvaluatesTo<Value>,
+    arms: Vec<(Pattern, EvaluatesTo<Value>)>,
+    default: Option<EvaluatesTo<Value>>,
+}
+
+impl MatchBuilder {
+    /// Sets the `scrutinee` expression.
+    pub fn evaluate<S: Into<EvaluatesTo<Value>>>(scrutinee: S) -> Self {
+        Self {
+            scrutinee: scrutinee.into(),
+            arms: Vec::new(),
+            default: None,
+        }
+    }
+
+    /// Appends an arm, tried after every arm added so far.
+    #[must_use]
+    pub fn arm<B: Into<EvaluatesTo<Value>>>(mut self, pattern: Pattern, body: B) -> Self {
+        self.arms.push((pattern, body.into()));
+        self
+    }
+
+    /// Sets the `default` expression, evaluated if no arm's pattern matches.
+    #[must_use]
+    pub fn default_expression<D: Into<EvaluatesTo<Value>>>(mut self, default: D) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Returns a [`Match`] expression, if `default` was set.
+    ///
+    /// # Errors
+    /// Fails if `default` hasn't been set.
+    pub fn build(self) -> Result<Match, &'static str> {
+        self.default
+            .map(|default| Match::new(self.scrutinee, self.arms, default))
+            .ok_or("Not all fields filled")
+    }
+}
+
+/// Multi-arm pattern match. Evaluates `scrutinee` once, then returns the
+/// body of the first `arms` entry whose [`Pattern`] matches it, trying
+/// arms top-to-bottom, or `default` if none do. Replaces a hand-nested
+/// `If` chain for state-machine-like dispatch.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Match {
+    /// Expression evaluated once and tested against each arm's pattern.
+    pub scrutinee: EvaluatesTo<Value>,
+    /// Arms tried top-to-bottom; the first whose pattern matches wins.
+    pub arms: Vec<(Pattern, EvaluatesTo<Value>)>,
+    /// Evaluated and returned if no arm's pattern matches.
+    pub default: EvaluatesTo<Value>,
+}
+
+impl Match {
+    /// Number of underneath expressions: the scrutinee, every pattern's
+    /// embedded expressions, every arm body, and the default, plus one.
+    pub fn len(&self) -> usize {
+        self.scrutinee.len()
+            + self
+                .arms
+                .iter()
+                .map(|(pattern, body)| pattern.len() + body.len())
+                .sum::<usize>()
+            + self.default.len()
+            + 1
+    }
+
+    /// Constructs a `Match` expression.
+    pub fn new<S, D>(scrutinee: S, arms: Vec<(Pattern, EvaluatesTo<Value>)>, default: D) -> Self
+    where
+        S: Into<EvaluatesTo<Value>>,
+        D: Into<EvaluatesTo<Value>>,
+    {
+        Self {
+            scrutinee: scrutinee.into(),
+            arms,
+            default: default.into(),
+        }
+    }
+}
+
+impl From<Match> for ExpressionBox {
+    fn from(match_expression: Match) -> Self {
+        Expression::Match(match_expression).into()
+    }
+}
+
+/// Coarse kind of a [`Value`], recorded in a [`FunctionSignature`]'s
+/// `params`/`return_type`. [`CallBuilder::build`] and
+/// [`FunctionRegistry::call`] check a [`Call`]'s argument count against
+/// `params`, not the argument kinds, and `return_type` is not checked; the
+/// builtins check their own arguments' kinds when called.
+///
+/// Assumes a companion `Value::Vec`/`Value::String` alongside the
+/// `Value::U32`/`Value::U128`/`Value::Fixed`/`Value::Bool` variants
+/// already assumed by [`NumericValue`] and the boolean expressions above.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// Any [`NumericValue`]-compatible width.
+    Numeric,
+    /// `Value::Bool`.
+    Bool,
+    /// `Value::String`.
+    String,
+    /// `Value::Vec`.
+    Vec,
+    /// `Value::Map`, i.e. a [`Context`].
+    Map,
+    /// No declared type: either a genuinely dynamic result (e.g.
+    /// [`Expression::Query`]), or a name not present in the
+    /// [`TypeEnvironment`] a [`Expression::validate_types`] walk was
+    /// given. Unifies with any other [`ValueKind`].
+    Unknown,
+}
+
+/// An error produced building or dispatching a [`Call`].
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum FunctionError {
+    /// No function is registered under this name.
+    #[display(fmt = "function `{_0}` is not registered")]
+    Unregistered(String),
+    /// `self.function` was called with the wrong number of arguments.
+    #[display(fmt = "function `{_0}` expects {_1} argument(s), got {_2}")]
+    Arity(String, usize, usize),
+    /// The argument at `_1` didn't match the registered [`ValueKind`].
+    #[display(fmt = "argument {_1} to function `{_0}` has the wrong kind")]
+    ArgumentKind(String, usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FunctionError {}
+
+/// Parameter/return [`ValueKind`]s for a registered function, together
+/// with the pure implementation [`FunctionRegistry::call`] dispatches to
+/// once every [`Call`] argument has been evaluated down to a [`Value`].
+#[derive(Debug, Clone)]
+pub struct FunctionSignature {
+    /// Expected kind of each positional argument, in order.
+    pub params: Vec<ValueKind>,
+    /// Kind of the value the function returns.
+    pub return_type: ValueKind,
+    implementation: fn(&[Value]) -> Result<Value, FunctionError>,
+}
+
+impl FunctionSignature {
+    /// Constructs a `FunctionSignature`.
+    pub const fn new(
+        params: Vec<ValueKind>,
+        return_type: ValueKind,
+        implementation: fn(&[Value]) -> Result<Value, FunctionError>,
+    ) -> Self {
+        Self {
+            params,
+            return_type,
+            implementation,
+        }
+    }
+}
+
+fn numeric_call_arg(function: &str, args: &[Value], index: usize) -> Result<NumericValue, FunctionError> {
+    NumericValue::try_from(args[index].clone())
+        .map_err(|_err| FunctionError::ArgumentKind(function.to_owned(), index))
+}
+
+fn vec_call_arg(function: &str, args: &[Value], index: usize) -> Result<Vec<Value>, FunctionError> {
+    match &args[index] {
+        Value::Vec(elements) => Ok(elements.clone()),
+        _ => Err(FunctionError::ArgumentKind(function.to_owned(), index)),
+    }
+}
+
+fn string_call_arg(function: &str, args: &[Value], index: usize) -> Result<String, FunctionError> {
+    match &args[index] {
+        Value::String(value) => Ok(value.clone()),
+        _ => Err(FunctionError::ArgumentKind(function.to_owned(), index)),
+    }
+}
+
+fn builtin_min(args: &[Value]) -> Result<Value, FunctionError> {
+    let (left, right) = (
+        numeric_call_arg("min", args, 0)?,
+        numeric_call_arg("min", args, 1)?,
+    );
+    Ok(core::cmp::min(left, right).into())
+}
+
+fn builtin_max(args: &[Value]) -> Result<Value, FunctionError> {
+    let (left, right) = (
+        numeric_call_arg("max", args, 0)?,
+        numeric_call_arg("max", args, 1)?,
+    );
+    Ok(core::cmp::max(left, right).into())
+}
+
+/// Every [`NumericValue`] width in this model is unsigned, so there's no
+/// sign to strip; `abs` is a pass-through kept for API symmetry with
+/// `min`/`max`.
+fn builtin_abs(args: &[Value]) -> Result<Value, FunctionError> {
+    numeric_call_arg("abs", args, 0).map(Into::into)
+}
+
+fn builtin_len(args: &[Value]) -> Result<Value, FunctionError> {
+    let elements = vec_call_arg("len", args, 0)?;
+    Ok(Value::U32(u32::try_from(elements.len()).unwrap_or(u32::MAX)))
+}
+
+fn builtin_union(args: &[Value]) -> Result<Value, FunctionError> {
+    let mut result = vec_call_arg("union", args, 0)?;
+    for element in vec_call_arg("union", args, 1)? {
+        if !result.contains(&element) {
+            result.push(element);
+        }
+    }
+    Ok(Value::Vec(result))
+}
+
+fn builtin_intersection(args: &[Value]) -> Result<Value, FunctionError> {
+    let left = vec_call_arg("intersection", args, 0)?;
+    let right = vec_call_arg("intersection", args, 1)?;
+    Ok(Value::Vec(
+        left.into_iter()
+            .filter(|element| right.contains(element))
+            .collect(),
+    ))
+}
+
+fn builtin_concat(args: &[Value]) -> Result<Value, FunctionError> {
+    let mut left = string_call_arg("concat", args, 0)?;
+    left.push_str(&string_call_arg("concat", args, 1)?);
+    Ok(Value::String(left))
+}
+
+fn builtin_to_lowercase(args: &[Value]) -> Result<Value, FunctionError> {
+    Ok(Value::String(
+        string_call_arg("to_lowercase", args, 0)?.to_lowercase(),
+    ))
+}
+
+/// Name-keyed table of callable functions. Ships with a standard set of
+/// pure builtins (numeric `min`/`max`/`abs`, collection
+/// `len`/`union`/`intersection`, string `concat`/`to_lowercase`); downstream
+/// operators extend the expression language without touching the
+/// [`Expression`] enum by [`FunctionRegistry::register`]ing more.
+#[derive(Debug, Clone)]
+pub struct FunctionRegistry {
+    functions: btree_map::BTreeMap<String, FunctionSignature>,
+}
+
+impl FunctionRegistry {
+    /// An empty registry, with no functions, not even the builtins.
+    pub fn new() -> Self {
+        Self {
+            functions: btree_map::BTreeMap::new(),
+        }
+    }
+
+    /// A registry pre-populated with the standard builtins.
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register(
+            "min",
+            FunctionSignature::new(vec![ValueKind::Numeric, ValueKind::Numeric], ValueKind::Numeric, builtin_min),
+        );
+        registry.register(
+            "max",
+            FunctionSignature::new(vec![ValueKind::Numeric, ValueKind::Numeric], ValueKind::Numeric, builtin_max),
+        );
+        registry.register(
+            "abs",
+            FunctionSignature::new(vec![ValueKind::Numeric], ValueKind::Numeric, builtin_abs),
+        );
+        registry.register(
+            "len",
+            FunctionSignature::new(vec![ValueKind::Vec], ValueKind::Numeric, builtin_len),
+        );
+        registry.register(
+            "union",
+            FunctionSignature::new(vec![ValueKind::Vec, ValueKind::Vec], ValueKind::Vec, builtin_union),
+        );
+        registry.register(
+            "intersection",
+            FunctionSignature::new(vec![ValueKind::Vec, ValueKind::Vec], ValueKind::Vec, builtin_intersection),
+        );
+        registry.register(
+            "concat",
+            FunctionSignature::new(vec![ValueKind::String, ValueKind::String], ValueKind::String, builtin_concat),
+        );
+        registry.register(
+            "to_lowercase",
+            FunctionSignature::new(vec![ValueKind::String], ValueKind::String, builtin_to_lowercase),
+        );
+        registry
+    }
+
+    /// Registers `signature` under `name`, overwriting any previous
+    /// function registered under the same name.
+    pub fn register(&mut self, name: impl Into<String>, signature: FunctionSignature) {
+        self.functions.insert(name.into(), signature);
+    }
+
+    /// Looks up the signature registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&FunctionSignature> {
+        self.functions.get(name)
+    }
+
+    /// Dispatches `call` against already-evaluated `args`.
+    ///
+    /// # Errors
+    /// Fails if `call.function` isn't registered, if `args.len()` doesn't
+    /// match the registered arity, or if an argument has the wrong kind.
+    pub fn call(&self, call: &Call, args: &[Value]) -> Result<Value, FunctionError> {
+        let signature = self
+            .get(&call.function)
+            .ok_or_else(|| FunctionError::Unregistered(call.function.clone()))?;
+        if signature.params.len() != args.len() {
+            return Err(FunctionError::Arity(
+                call.function.clone(),
+                signature.params.len(),
+                args.len(),
+            ));
+        }
+        (signature.implementation)(args)
+    }
+}
+
+impl Default for FunctionRegistry {
+    fn default() -> Self {
+        Self::with_builtins()
+    }
+}
+
+/// Builder for [`Call`] expression.
+#[derive(Debug)]
+#[must_use = ".build() not used"]
+pub struct CallBuilder {
+    function: String,
+    args: Vec<EvaluatesTo<Value>>,
+}
+
+impl CallBuilder {
+    /// Names the function to call.
+    pub fn function(function: impl Into<String>) -> Self {
+        Self {
+            function: function.into(),
+            args: Vec::new(),
+        }
+    }
+
+    /// Appends an argument, evaluated in call order.
+    #[must_use]
+    pub fn with_arg<A: Into<EvaluatesTo<Value>>>(mut self, arg: A) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Returns a [`Call`] expression, checking `self.args`' arity against
+    /// `registry`'s signature for `self.function`.
+    ///
+    /// # Errors
+    /// Fails if `self.function` isn't registered in `registry`, or isn't
+    /// registered to take `self.args.len()` arguments.
+    pub fn build(self, registry: &FunctionRegistry) -> Result<Call, FunctionError> {
+        let signature = registry
+            .get(&self.function)
+            .ok_or_else(|| FunctionError::Unregistered(self.function.clone()))?;
+        if signature.params.len() != self.args.len() {
+            return Err(FunctionError::Arity(
+                self.function.clone(),
+                signature.params.len(),
+                self.args.len(),
+            ));
+        }
+        Ok(Call {
+            function: self.function,
+            args: self.args,
+        })
+    }
+}
+
+/// Call to a named function, resolved and dispatched through a
+/// [`FunctionRegistry`]. Lets downstream operators extend the expression
+/// language (string concatenation, `min`/`max`, collection `len`, ...)
+/// without adding a variant to the closed [`Expression`] enum.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Call {
+    /// Name the function is registered under in the [`FunctionRegistry`]
+    /// this `Call` will be dispatched through.
+    pub function: String,
+    /// Arguments, evaluated in order before dispatch.
+    pub args: Vec<EvaluatesTo<Value>>,
+}
+
+impl Call {
+    /// Number of underneath expressions: the sum of every argument's,
+    /// plus one.
+    pub fn len(&self) -> usize {
+        self.args.iter().map(EvaluatesTo::len).sum::<usize>() + 1
+    }
+}
+
+impl From<Call> for ExpressionBox {
+    fn from(call: Call) -> Self {
+        Expression::Call(call).into()
+    }
+}
+
+/// Buckets a collection before aggregating: one key tuple per element is
+/// computed by evaluating `key_expressions` with the element bound under
+/// [`GroupBy::ELEMENT_NAME`] in the evaluation context, the same way a
+/// [`Where`]-bound name is looked up via [`ContextValue`].
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct GroupBy {
+    /// Expressions evaluated per-element to build that element's group key.
+    pub key_expressions: Vec<ExpressionBox>,
+}
+
+impl GroupBy {
+    /// Name the current element is bound to while `key_expressions` are
+    /// evaluated.
+    pub const ELEMENT_NAME: &'static str = "element";
+
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.key_expressions.iter().map(|key| key.len()).sum::<usize>() + 1
+    }
+
+    /// Constructs a `GroupBy`.
+    pub fn new(key_expressions: Vec<ExpressionBox>) -> Self {
+        Self { key_expressions }
+    }
+}
+
+/// Shared shape of every aggregate [`Expression`] variant: the collection
+/// to reduce, and an optional [`GroupBy`] to bucket it by first.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Aggregate {
+    /// Collection to reduce.
+    pub input: EvaluatesTo<Vec<Value>>,
+    /// Bucketing to apply before reducing, if any; `None` reduces the
+    /// whole collection into a single bucket.
+    pub group_by: Option<GroupBy>,
+}
+
+impl Aggregate {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.input.len() + self.group_by.as_ref().map_or(0, GroupBy::len) + 1
+    }
+
+    /// Constructs an `Aggregate`.
+    pub fn new<I: Into<EvaluatesTo<Vec<Value>>>>(input: I, group_by: Option<GroupBy>) -> Self {
+        Self {
+            input: input.into(),
+            group_by,
+        }
+    }
+}
+
+/// Which reduction [`Aggregate::finish`]es a bucket's running state into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateKind {
+    /// Number of elements in the bucket.
+    Count,
+    /// Sum of the bucket's elements.
+    Sum,
+    /// Smallest of the bucket's elements.
+    Min,
+    /// Largest of the bucket's elements.
+    Max,
+    /// Arithmetic mean of the bucket's elements.
+    Average,
+}
+
+/// An error produced folding an element into, or finalizing, a bucket's
+/// running [`AggregateKind`] state.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum AggregateError {
+    /// `Sum`/`Min`/`Max`/`Average` have no well-defined result over an
+    /// empty bucket; only `Count` does (`0`).
+    #[display(fmt = "aggregate has no result over an empty bucket")]
+    EmptyBucket,
+    /// An element wasn't a [`NumericValue`], where one was required.
+    #[display(fmt = "aggregated element is not numeric")]
+    NotNumeric,
+    /// Folding two numeric elements together failed, e.g. on overflow.
+    #[display(fmt = "{_0}")]
+    Numeric(NumericError),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AggregateError {}
+
+impl From<NumericError> for AggregateError {
+    fn from(error: NumericError) -> Self {
+        Self::Numeric(error)
+    }
+}
+
+/// Running per-bucket state while folding a collection for one
+/// [`AggregateKind`].
+#[derive(Debug, Clone)]
+enum Accumulator {
+    Count(u64),
+    Sum(Option<NumericValue>),
+    Min(Option<NumericValue>),
+    Max(Option<NumericValue>),
+    Average(Option<(NumericValue, u64)>),
+}
+
+impl Accumulator {
+    fn new(kind: AggregateKind) -> Self {
+        match kind {
+            AggregateKind::Count => Self::Count(0),
+            AggregateKind::Sum => Self::Sum(None),
+            AggregateKind::Min => Self::Min(None),
+            AggregateKind::Max => Self::Max(None),
+            AggregateKind::Average => Self::Average(None),
+        }
+    }
+
+    fn fold(&mut self, value: &Value) -> Result<(), AggregateError> {
+        match self {
+            Self::Count(count) => {
+                *count += 1;
+                Ok(())
+            }
+            Self::Sum(running) => {
+                let value = NumericValue::try_from(value.clone()).map_err(|_err| AggregateError::NotNumeric)?;
+                *running = Some(match running.take() {
+                    Some(current) => current.checked_add(value)?,
+                    None => value,
+                });
+                Ok(())
+            }
+            Self::Min(running) => {
+                let value = NumericValue::try_from(value.clone()).map_err(|_err| AggregateError::NotNumeric)?;
+                *running = Some(match running.take() {
+                    Some(current) => core::cmp::min(current, value),
+                    None => value,
+                });
+                Ok(())
+            }
+            Self::Max(running) => {
+                let value = NumericValue::try_from(value.clone()).map_err(|_err| AggregateError::NotNumeric)?;
+                *running = Some(match running.take() {
+                    Some(current) => core::cmp::max(current, value),
+                    None => value,
+                });
+                Ok(())
+            }
+            Self::Average(running) => {
+                let value = NumericValue::try_from(value.clone()).map_err(|_err| AggregateError::NotNumeric)?;
+                *running = Some(match running.take() {
+                    Some((sum, count)) => (sum.checked_add(value)?, count + 1),
+                    None => (value, 1),
+                });
+                Ok(())
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Value, AggregateError> {
+        match self {
+            Self::Count(count) => Ok(Value::U128(count.into())),
+            Self::Sum(running) | Self::Min(running) | Self::Max(running) => {
+                running.map(Into::into).ok_or(AggregateError::EmptyBucket)
+            }
+            Self::Average(running) => {
+                let (sum, count) = running.ok_or(AggregateError::EmptyBucket)?;
+                let divisor = match sum {
+                    NumericValue::Fixed(_) => NumericValue::Fixed(Fixed::new(count.into(), 0)),
+                    NumericValue::U32(_) | NumericValue::U128(_) => NumericValue::U128(count.into()),
+                };
+                Ok(sum.checked_div(divisor)?.into())
+            }
+        }
+    }
+}
+
+/// Folds `keyed_elements` — each collection element already paired with
+/// its evaluated [`GroupBy`] key tuple — into one running [`Accumulator`]
+/// per distinct key, then finalizes every bucket into an output row: the
+/// group key values followed by the aggregate's result. Empty input is
+/// finalized as a single empty bucket with an empty key.
+///
+/// # Errors
+/// Fails per [`AggregateError`] if `kind` has no well-defined result over
+/// an empty bucket, or an element isn't numeric where one is required.
+pub fn aggregate_buckets(
+    kind: AggregateKind,
+    keyed_elements: impl IntoIterator<Item = (Vec<Value>, Value)>,
+) -> Result<Vec<Vec<Value>>, AggregateError> {
+    let mut buckets: btree_map::BTreeMap<Vec<Value>, Accumulator> = btree_map::BTreeMap::new();
+    for (key, value) in keyed_elements {
+        buckets
+            .entry(key)
+            .or_insert_with(|| Accumulator::new(kind))
+            .fold(&value)?;
+    }
+    if buckets.is_empty() {
+        buckets.insert(Vec::new(), Accumulator::new(kind));
+    }
+    buckets
+        .into_iter()
+        .map(|(mut key, accumulator)| {
+            key.push(accumulator.finish()?);
+            Ok(key)
+        })
+        .collect()
+}
+
+/// Sum of a collection, optionally bucketed by [`GroupBy`]. `0` elements
+/// have no sum; use [`Count`] to count an empty collection.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Sum {
+    /// Collection to sum, and how to bucket it.
+    pub aggregate: Aggregate,
+}
+
+impl Sum {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.aggregate.len()
+    }
+
+    /// Constructs a `Sum` expression.
+    pub fn new<I: Into<EvaluatesTo<Vec<Value>>>>(input: I, group_by: Option<GroupBy>) -> Self {
+        Self {
+            aggregate: Aggregate::new(input, group_by),
+        }
+    }
+}
+
+impl From<Sum> for ExpressionBox {
+    fn from(sum: Sum) -> Self {
+        Expression::Sum(sum).into()
+    }
+}
+
+/// Count of the elements in a collection, optionally bucketed by
+/// [`GroupBy`]. Unlike the other aggregates, an empty bucket has a
+/// well-defined count of `0`.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Count {
+    /// Collection to count, and how to bucket it.
+    pub aggregate: Aggregate,
+}
+
+impl Count {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.aggregate.len()
+    }
+
+    /// Constructs a `Count` expression.
+    pub fn new<I: Into<EvaluatesTo<Vec<Value>>>>(input: I, group_by: Option<GroupBy>) -> Self {
+        Self {
+            aggregate: Aggregate::new(input, group_by),
+        }
+    }
+}
+
+impl From<Count> for ExpressionBox {
+    fn from(count: Count) -> Self {
+        Expression::Count(count).into()
+    }
+}
+
+/// Minimum of a collection, optionally bucketed by [`GroupBy`]. `0`
+/// elements have no minimum.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Min {
+    /// Collection to minimize over, and how to bucket it.
+    pub aggregate: Aggregate,
+}
+
+impl Min {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.aggregate.len()
+    }
+
+    /// Constructs a `Min` expression.
+    pub fn new<I: Into<EvaluatesTo<Vec<Value>>>>(input: I, group_by: Option<GroupBy>) -> Self {
+        Self {
+            aggregate: Aggregate::new(input, group_by),
+        }
+    }
+}
+
+impl From<Min> for ExpressionBox {
+    fn from(min: Min) -> Self {
+        Expression::Min(min).into()
+    }
+}
+
+/// Maximum of a collection, optionally bucketed by [`GroupBy`]. `0`
+/// elements have no maximum.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Max {
+    /// Collection to maximize over, and how to bucket it.
+    pub aggregate: Aggregate,
+}
+
+impl Max {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.aggregate.len()
+    }
+
+    /// Constructs a `Max` expression.
+    pub fn new<I: Into<EvaluatesTo<Vec<Value>>>>(input: I, group_by: Option<GroupBy>) -> Self {
+        Self {
+            aggregate: Aggregate::new(input, group_by),
+        }
+    }
+}
+
+impl From<Max> for ExpressionBox {
+    fn from(max: Max) -> Self {
+        Expression::Max(max).into()
+    }
+}
+
+/// Arithmetic mean of a collection, optionally bucketed by [`GroupBy`].
+/// `0` elements have no average.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct Average {
+    /// Collection to average, and how to bucket it.
+    pub aggregate: Aggregate,
+}
+
+impl Average {
+    /// Number of underneath expressions.
+    pub fn len(&self) -> usize {
+        self.aggregate.len()
+    }
+
+    /// Constructs an `Average` expression.
+    pub fn new<I: Into<EvaluatesTo<Vec<Value>>>>(input: I, group_by: Option<GroupBy>) -> Self {
+        Self {
+            aggregate: Aggregate::new(input, group_by),
+        }
+    }
+}
+
+impl From<Average> for ExpressionBox {
+    fn from(average: Average) -> Self {
+        Expression::Average(average).into()
+    }
+}
+
 /// `Contains` expression.
 /// Returns `true` if `collection` contains an `element`, `false` otherwise.
 #[derive(
@@ -815,6 +2608,9 @@ pub struct WhereBuilder {
     expression: EvaluatesTo<Value>,
     /// Context values for the context binded to their `String` names.
     values: btree_map::BTreeMap<ValueName, EvaluatesTo<Value>>,
+    /// Ordered let-bindings, evaluated top-to-bottom, each one seeing every
+    /// earlier binding in this `Vec`. See [`Where::sequential_values`].
+    sequential_values: Vec<(ValueName, EvaluatesTo<Value>)>,
 }
 
 impl WhereBuilder {
@@ -824,10 +2620,15 @@ impl WhereBuilder {
         Self {
             expression: expression.into(),
             values: btree_map::BTreeMap::new(),
+            sequential_values: Vec::new(),
         }
     }
 
     /// Binds `expression` result to a `value_name`, by which it will be reachable from the main expression.
+    ///
+    /// All values bound this way are evaluated independently against the
+    /// outer context, in no particular order. To let a later binding see an
+    /// earlier one, use [`Self::bind_seq`] instead.
     #[must_use]
     pub fn with_value<E: Into<EvaluatesTo<Value>>>(
         mut self,
@@ -838,11 +2639,25 @@ impl WhereBuilder {
         self
     }
 
+    /// Binds `expression` result to a `value_name` as the next step of a
+    /// let-chain: `expression` is evaluated against the outer context
+    /// augmented with every binding added through this method so far,
+    /// turning repeated calls into `let name = expr; ...` semantics.
+    #[must_use]
+    pub fn bind_seq<E: Into<EvaluatesTo<Value>>>(
+        mut self,
+        value_name: ValueName,
+        expression: E,
+    ) -> Self {
+        self.sequential_values.push((value_name, expression.into()));
+        self
+    }
+
     /// Returns a [`Where`] expression.
     #[inline]
     #[must_use]
     pub fn build(self) -> Where {
-        Where::new(self.expression, self.values)
+        Where::new(self.expression, self.values, self.sequential_values)
     }
 }
 
@@ -856,6 +2671,14 @@ pub struct Where {
     pub expression: EvaluatesTo<Value>,
     /// Context values for the context binded to their `String` names.
     pub values: btree_map::BTreeMap<ValueName, EvaluatesTo<Value>>,
+    /// Ordered let-bindings, evaluated top-to-bottom after `values`, each
+    /// one's expression seeing every earlier sequential binding (but not
+    /// later ones) in its context. This turns `Where` into a proper
+    /// let-chain instead of requiring deeply nested `Where` expressions
+    /// when one intermediate computation feeds the next. Referencing a
+    /// sequential binding's name before it was evaluated is an evaluation
+    /// error, not resolved here.
+    pub sequential_values: Vec<(ValueName, EvaluatesTo<Value>)>,
 }
 
 impl Where {
@@ -863,7 +2686,14 @@ impl Where {
     #[must_use]
     #[inline]
     pub fn len(&self) -> usize {
-        self.expression.len() + self.values.values().map(EvaluatesTo::len).sum::<usize>() + 1
+        self.expression.len()
+            + self.values.values().map(EvaluatesTo::len).sum::<usize>()
+            + self
+                .sequential_values
+                .iter()
+                .map(|(_, value)| value.len())
+                .sum::<usize>()
+            + 1
     }
 
     /// Constructs `Or` expression.
@@ -871,10 +2701,12 @@ impl Where {
     pub fn new<E: Into<EvaluatesTo<Value>>>(
         expression: E,
         values: btree_map::BTreeMap<ValueName, EvaluatesTo<Value>>,
+        sequential_values: Vec<(ValueName, EvaluatesTo<Value>)>,
     ) -> Self {
         Self {
             expression: expression.into(),
             values,
+            sequential_values,
         }
     }
 }
@@ -885,6 +2717,94 @@ impl From<Where> for ExpressionBox {
     }
 }
 
+/// How [`merge_contexts`] resolves a key bound in both operands.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub enum ConflictPolicy {
+    /// The right-hand context's value for a shared key wins.
+    PreferRight,
+    /// A shared key is rejected outright rather than silently resolved.
+    Error,
+}
+
+/// Failure merging two [`Context`]s under a [`ConflictPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, Display)]
+pub enum ContextMergeError {
+    /// [`ConflictPolicy::Error`] rejected a key bound in both operands.
+    #[display(fmt = "key `{_0}` is bound in both contexts")]
+    DuplicateKey(ValueName),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ContextMergeError {}
+
+/// Folds `right` into `left`, producing the union of their bindings. Under
+/// [`ConflictPolicy::PreferRight`] a key present in both operands takes
+/// `right`'s value; under [`ConflictPolicy::Error`] that same case is
+/// rejected instead of silently picking a winner.
+pub fn merge_contexts(
+    mut left: Context,
+    right: Context,
+    policy: ConflictPolicy,
+) -> Result<Context, ContextMergeError> {
+    for (name, value) in right {
+        if policy == ConflictPolicy::Error && left.contains_key(&name) {
+            return Err(ContextMergeError::DuplicateKey(name));
+        }
+        left.insert(name, value);
+    }
+    Ok(left)
+}
+
+/// Combines two context-producing sub-expressions into one binding map, so
+/// a [`Where`] body can be evaluated against the union of several reusable
+/// scopes (e.g. a shared "account facts" context merged with a
+/// per-instruction context) instead of rebuilding the full binding set at
+/// every call site. Both operands are expected to evaluate to
+/// [`Value::Map`]; see [`merge_contexts`] for how `policy` resolves a key
+/// bound by both.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Decode, Encode, Deserialize, Serialize, IntoSchema, PartialOrd, Ord,
+)]
+pub struct ContextMerge {
+    /// Left-hand context-producing expression.
+    pub left: EvaluatesTo<Value>,
+    /// Right-hand context-producing expression.
+    pub right: EvaluatesTo<Value>,
+    /// How a key bound by both operands is resolved.
+    pub policy: ConflictPolicy,
+}
+
+impl ContextMerge {
+    /// Number of underneath expressions.
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.left.len() + self.right.len() + 1
+    }
+
+    /// Constructs a `ContextMerge` expression.
+    #[must_use]
+    pub fn new<L, R>(left: L, right: R, policy: ConflictPolicy) -> Self
+    where
+        L: Into<EvaluatesTo<Value>>,
+        R: Into<EvaluatesTo<Value>>,
+    {
+        Self {
+            left: left.into(),
+            right: right.into(),
+            policy,
+        }
+    }
+}
+
+impl From<ContextMerge> for ExpressionBox {
+    fn from(context_merge: ContextMerge) -> Self {
+        Expression::ContextMerge(context_merge).into()
+    }
+}
+
 impl QueryBox {
     /// Number of underneath expressions.
     pub const fn len(&self) -> usize {
@@ -898,11 +2818,308 @@ impl From<QueryBox> for ExpressionBox {
     }
 }
 
+/// Parses expressions from a textual infix form, e.g.
+/// `account_balance > 10 && !(x % 2 == 0)`, into the same [`Expression`]
+/// variants the `Add::new`/`IfBuilder`/`WhereBuilder`/etc. constructors
+/// build, using precedence climbing (a.k.a. Pratt parsing).
+pub mod parser {
+    use super::*;
+
+    /// An error produced while parsing an infix expression.
+    #[derive(Debug, Clone, PartialEq, Eq, Display)]
+    pub struct ParseError(String);
+
+    #[cfg(feature = "std")]
+    impl std::error::Error for ParseError {}
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum Token {
+        Number(u32),
+        Ident(String),
+        True,
+        False,
+        LParen,
+        RParen,
+        Comma,
+        Bang,
+        Op(Op),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        Or,
+        And,
+        Eq,
+        Gt,
+        Lt,
+        Ge,
+        Le,
+        Add,
+        Sub,
+        Mul,
+        Div,
+        Mod,
+        Pow,
+    }
+
+    fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+        let chars: Vec<char> = input.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            match chars[i] {
+                c if c.is_whitespace() => i += 1,
+                '(' => {
+                    tokens.push(Token::LParen);
+                    i += 1;
+                }
+                ')' => {
+                    tokens.push(Token::RParen);
+                    i += 1;
+                }
+                ',' => {
+                    tokens.push(Token::Comma);
+                    i += 1;
+                }
+                '+' => {
+                    tokens.push(Token::Op(Op::Add));
+                    i += 1;
+                }
+                '-' => {
+                    tokens.push(Token::Op(Op::Sub));
+                    i += 1;
+                }
+                '%' => {
+                    tokens.push(Token::Op(Op::Mod));
+                    i += 1;
+                }
+                '/' => {
+                    tokens.push(Token::Op(Op::Div));
+                    i += 1;
+                }
+                '*' if chars.get(i + 1) == Some(&'*') => {
+                    tokens.push(Token::Op(Op::Pow));
+                    i += 2;
+                }
+                '*' => {
+                    tokens.push(Token::Op(Op::Mul));
+                    i += 1;
+                }
+                '>' if chars.get(i + 1) == Some(&'=') => {
+                    tokens.push(Token::Op(Op::Ge));
+                    i += 2;
+                }
+                '>' => {
+                    tokens.push(Token::Op(Op::Gt));
+                    i += 1;
+                }
+                '<' if chars.get(i + 1) == Some(&'=') => {
+                    tokens.push(Token::Op(Op::Le));
+                    i += 2;
+                }
+                '<' => {
+                    tokens.push(Token::Op(Op::Lt));
+                    i += 1;
+                }
+                '=' if chars.get(i + 1) == Some(&'=') => {
+                    tokens.push(Token::Op(Op::Eq));
+                    i += 2;
+                }
+                '&' if chars.get(i + 1) == Some(&'&') => {
+                    tokens.push(Token::Op(Op::And));
+                    i += 2;
+                }
+                '|' if chars.get(i + 1) == Some(&'|') => {
+                    tokens.push(Token::Op(Op::Or));
+                    i += 2;
+                }
+                '!' => {
+                    tokens.push(Token::Bang);
+                    i += 1;
+                }
+                c if c.is_ascii_digit() => {
+                    let start = i;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    let text: String = chars[start..i].iter().collect();
+                    let number = text
+                        .parse::<u32>()
+                        .map_err(|_| ParseError(format!("Invalid number literal `{text}`")))?;
+                    tokens.push(Token::Number(number));
+                }
+                c if c.is_alphabetic() || c == '_' => {
+                    let start = i;
+                    while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                        i += 1;
+                    }
+                    let text: String = chars[start..i].iter().collect();
+                    tokens.push(match text.as_str() {
+                        "true" => Token::True,
+                        "false" => Token::False,
+                        _ => Token::Ident(text),
+                    });
+                }
+                c => return Err(ParseError(format!("Unexpected character `{c}` at character {i}"))),
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    struct Parser {
+        tokens: Vec<Token>,
+        pos: usize,
+    }
+
+    impl Parser {
+        fn peek_op(&self) -> Option<Op> {
+            match self.tokens.get(self.pos) {
+                Some(Token::Op(op)) => Some(*op),
+                _ => None,
+            }
+        }
+
+        fn next(&mut self) -> Option<Token> {
+            let token = self.tokens.get(self.pos).cloned();
+            self.pos += 1;
+            token
+        }
+
+        /// `(left binding power, right binding power)` for a binary
+        /// operator. Left-associative operators use `right_bp = left_bp +
+        /// 1`; `**` is right-associative, so its right bp is lower than its
+        /// left bp.
+        const fn binding_power(op: Op) -> (u8, u8) {
+            match op {
+                Op::Or => (1, 2),
+                Op::And => (3, 4),
+                Op::Eq | Op::Gt | Op::Lt | Op::Ge | Op::Le => (5, 6),
+                Op::Add | Op::Sub => (7, 8),
+                Op::Mul | Op::Div | Op::Mod => (9, 10),
+                Op::Pow => (12, 11),
+            }
+        }
+
+        fn apply_op(op: Op, lhs: ExpressionBox, rhs: ExpressionBox) -> ExpressionBox {
+            match op {
+                Op::Or => Or::new(lhs, rhs).into(),
+                Op::And => And::new(lhs, rhs).into(),
+                Op::Eq => Equal::new(lhs, rhs).into(),
+                Op::Gt => Greater::new(lhs, rhs).into(),
+                Op::Lt => Less::new(lhs, rhs).into(),
+                // `>=`/`<=` aren't their own variants: compose them from
+                // the existing `Less`/`Greater`/`Not`.
+                Op::Ge => Not::new(Less::new(lhs, rhs)).into(),
+                Op::Le => Not::new(Greater::new(lhs, rhs)).into(),
+                Op::Add => Add::new(lhs, rhs).into(),
+                Op::Sub => Subtract::new(lhs, rhs).into(),
+                Op::Mul => Multiply::new(lhs, rhs).into(),
+                Op::Div => Divide::new(lhs, rhs).into(),
+                Op::Mod => Mod::new(lhs, rhs).into(),
+                Op::Pow => RaiseTo::new(lhs, rhs).into(),
+            }
+        }
+
+        fn parse_primary(&mut self) -> Result<ExpressionBox, ParseError> {
+            match self.next() {
+                Some(Token::Number(number)) => Ok(ExpressionBox::from(number)),
+                Some(Token::True) => Ok(ExpressionBox::from(true)),
+                Some(Token::False) => Ok(ExpressionBox::from(false)),
+                Some(Token::Bang) => {
+                    // Binds tighter than every binary operator except
+                    // `**`: `!a ** b` parses as `!(a ** b)`.
+                    let expression = self.parse_expr(11)?;
+                    Ok(Not::new(expression).into())
+                }
+                Some(Token::LParen) => {
+                    let expression = self.parse_expr(0)?;
+                    match self.next() {
+                        Some(Token::RParen) => Ok(expression),
+                        other => Err(ParseError(format!("Expected `)`, found {other:?}"))),
+                    }
+                }
+                Some(Token::Ident(name)) if name == "contains" && self.peek_paren() => {
+                    self.next(); // `(`
+                    let collection = self.parse_expr(0)?;
+                    self.expect(Token::Comma)?;
+                    let element = self.parse_expr(0)?;
+                    self.expect(Token::RParen)?;
+                    Ok(Contains::new(collection, element).into())
+                }
+                Some(Token::Ident(name)) => Ok(ContextValue::new(&name).into()),
+                other => Err(ParseError(format!("Expected a value, found {other:?}"))),
+            }
+        }
+
+        fn peek_paren(&self) -> bool {
+            matches!(self.tokens.get(self.pos), Some(Token::LParen))
+        }
+
+        fn expect(&mut self, expected: Token) -> Result<(), ParseError> {
+            match self.next() {
+                Some(token) if token == expected => Ok(()),
+                other => Err(ParseError(format!("Expected {expected:?}, found {other:?}"))),
+            }
+        }
+
+        fn parse_expr(&mut self, min_bp: u8) -> Result<ExpressionBox, ParseError> {
+            let mut lhs = self.parse_primary()?;
+
+            while let Some(op) = self.peek_op() {
+                let (left_bp, right_bp) = Self::binding_power(op);
+                if left_bp < min_bp {
+                    break;
+                }
+                self.next();
+                let rhs = self.parse_expr(right_bp)?;
+                lhs = Self::apply_op(op, lhs, rhs);
+            }
+
+            Ok(lhs)
+        }
+    }
+
+    /// Parses a textual infix expression into an [`ExpressionBox`].
+    ///
+    /// # Errors
+    /// Fails if `input` isn't a well-formed expression.
+    pub fn parse_expression(input: &str) -> Result<ExpressionBox, ParseError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expression = parser.parse_expr(0)?;
+
+        if parser.pos != parser.tokens.len() {
+            return Err(ParseError(format!(
+                "Unexpected trailing input at token {}",
+                parser.pos
+            )));
+        }
+
+        Ok(expression)
+    }
+
+    impl core::str::FromStr for ExpressionBox {
+        type Err = ParseError;
+
+        fn from_str(input: &str) -> Result<Self, Self::Err> {
+            parse_expression(input)
+        }
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     pub use super::{
-        Add, And, Contains, ContainsAll, ContainsAny, Context, ContextValue, Divide, Equal,
-        EvaluatesTo, Expression, ExpressionBox, Greater, If as IfExpression, IfBuilder, Less, Mod,
-        Multiply, Not, Or, RaiseTo, Subtract, ValueName, Where, WhereBuilder,
+        parser::{parse_expression, ParseError},
+        Add, Aggregate, AggregateError, AggregateKind, Average, And, Call, CallBuilder,
+        ConflictPolicy, Contains, ContainsAll, ContainsAny, Context, ContextMerge,
+        ContextMergeError, ContextValue, Count, Divide, Equal, EvaluatesTo, Expression,
+        ExpressionBox, Fixed, FunctionError, FunctionRegistry, FunctionSignature, Greater,
+        GroupBy, If as IfExpression, IfBuilder, Less, Match, MatchBuilder, Max, Min, Mod,
+        Multiply, NumericError, NumericValue, Not, Or, Pattern, QualifiedName, RaiseTo, Subtract,
+        Sum, TypeError, TypeEnvironment, ValueKind, ValueName, Where, WhereBuilder,
+        aggregate_buckets, flatten_keys, get_entry, merge_contexts, set_entry,
     };
 }
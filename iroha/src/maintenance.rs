@@ -4,7 +4,7 @@
 use crate::config::Configuration;
 use async_std::task;
 use iroha_derive::Io;
-use iroha_error::Result;
+use iroha_error::{Result, WrapErr};
 use parity_scale_codec::{Decode, Encode};
 
 /// Entry point and main entity in `maintenance` API.
@@ -22,7 +22,7 @@ impl System {
         }
     }
 
-    /// Scrape current system metrics.
+    /// Scrape current host metrics (cpu, disk, memory).
     ///
     /// # Errors
     ///
@@ -31,6 +31,49 @@ impl System {
         metrics.calculate()?;
         Ok(metrics)
     }
+
+    /// Scrape host metrics and augment them with blockchain-level gauges
+    /// (block height, transaction counts, queue length, peer count) pulled
+    /// from `source`.
+    ///
+    /// `source` is implemented by whatever holds the live `WorldStateView`
+    /// and `Queue` handles (e.g. `Torii`); `maintenance` itself stays
+    /// decoupled from `iroha_core` so this module can be reused standalone.
+    ///
+    /// # Errors
+    /// Can fail during cpu and memory usage calculations
+    pub fn scrape_metrics_with_blockchain(
+        &self,
+        source: &dyn BlockchainMetricsSource,
+    ) -> Result<Metrics> {
+        let mut metrics = self.scrape_metrics()?;
+        metrics.blockchain = BlockchainMetrics {
+            block_height: source.block_height(),
+            committed_transactions: source.committed_transactions(),
+            rejected_transactions: source.rejected_transactions(),
+            queue_size: source.queue_size(),
+            connected_peers: source.connected_peers(),
+        };
+        Ok(metrics)
+    }
+}
+
+/// Supplies the blockchain-level figures that [`System::scrape_metrics_with_blockchain`]
+/// publishes alongside host metrics.
+///
+/// A `GET /metrics` handler in `Torii` is expected to implement this over its
+/// `WorldStateView` and `Queue` handles and pass itself through.
+pub trait BlockchainMetricsSource {
+    /// Height of the latest committed block.
+    fn block_height(&self) -> u64;
+    /// Total number of committed transactions since genesis.
+    fn committed_transactions(&self) -> u64;
+    /// Total number of rejected transactions since genesis.
+    fn rejected_transactions(&self) -> u64;
+    /// Number of transactions currently waiting in the queue.
+    fn queue_size(&self) -> u64;
+    /// Number of peers currently connected over the network.
+    fn connected_peers(&self) -> u64;
 }
 
 /// `Health` enumerates different variants of Iroha `Peer` states.
@@ -43,6 +86,21 @@ pub enum Health {
     Ready,
 }
 
+/// Blockchain-level gauges published alongside host metrics.
+#[derive(Clone, Copy, Debug, Default, Io, Encode, Decode)]
+pub struct BlockchainMetrics {
+    /// Height of the latest committed block.
+    pub block_height: u64,
+    /// Total number of committed transactions since genesis.
+    pub committed_transactions: u64,
+    /// Total number of rejected transactions since genesis.
+    pub rejected_transactions: u64,
+    /// Number of transactions currently waiting in the queue.
+    pub queue_size: u64,
+    /// Number of peers currently connected over the network.
+    pub connected_peers: u64,
+}
+
 /// Metrics struct compose all Iroha metrics and provides an ability to export them in monitoring
 /// systems.
 #[derive(Clone, Debug, Default, Io, Encode, Decode)]
@@ -50,6 +108,7 @@ pub struct Metrics {
     cpu: cpu::Cpu,
     disk: disk::Disk,
     memory: memory::Memory,
+    blockchain: BlockchainMetrics,
 }
 
 impl Metrics {
@@ -73,6 +132,52 @@ impl Metrics {
         })?;
         Ok(())
     }
+
+    /// Serializes `self` with SCALE, preserving the original wire format for
+    /// clients that decode metrics with `parity_scale_codec` rather than
+    /// scraping the Prometheus text endpoint.
+    pub fn encode_scale(&self) -> Vec<u8> {
+        self.encode()
+    }
+
+    /// Renders `self` in the Prometheus text exposition format: every gauge
+    /// is preceded by a `# TYPE iroha_<name> gauge` line and followed by a
+    /// single `iroha_<name>{peer="<peer_id>"} <value>` sample.
+    pub fn to_prometheus_text(&self, peer_id: &str) -> String {
+        let mut text = String::new();
+        macro_rules! gauge {
+            ($name:expr, $value:expr) => {
+                text.push_str(&format!("# TYPE iroha_{} gauge\n", $name));
+                text.push_str(&format!(
+                    "iroha_{}{{peer=\"{}\"}} {}\n",
+                    $name, peer_id, $value
+                ));
+            };
+        }
+
+        gauge!("cpu_frequency_hz", self.cpu.load.frequency_hz);
+        gauge!("cpu_load_average_1m", self.cpu.load.load_average_1);
+        gauge!("cpu_load_average_5m", self.cpu.load.load_average_5);
+        gauge!("cpu_load_average_15m", self.cpu.load.load_average_15);
+        gauge!("disk_block_storage_bytes", self.disk.block_storage_size);
+        gauge!("memory_used_bytes", self.memory.memory.used_bytes);
+        gauge!("memory_total_bytes", self.memory.memory.total_bytes);
+        gauge!("swap_used_bytes", self.memory.swap.used_bytes);
+        gauge!("swap_total_bytes", self.memory.swap.total_bytes);
+        gauge!("block_height", self.blockchain.block_height);
+        gauge!(
+            "committed_transactions",
+            self.blockchain.committed_transactions
+        );
+        gauge!(
+            "rejected_transactions",
+            self.blockchain.rejected_transactions
+        );
+        gauge!("queue_size", self.blockchain.queue_size);
+        gauge!("connected_peers", self.blockchain.connected_peers);
+
+        text
+    }
 }
 
 mod disk {
@@ -84,8 +189,8 @@ mod disk {
 
     #[derive(Clone, Debug, Default, Io, Encode, Decode)]
     pub struct Disk {
-        block_storage_size: u64,
-        block_storage_path: String,
+        pub block_storage_size: u64,
+        pub block_storage_path: String,
     }
 
     impl Disk {
@@ -116,14 +221,14 @@ mod disk {
 }
 
 mod cpu {
-    use heim::cpu;
+    use heim::{cpu, units::frequency::hertz};
     use iroha_derive::Io;
-    use iroha_error::Result;
+    use iroha_error::{Result, WrapErr};
     use parity_scale_codec::{Decode, Encode};
 
     #[derive(Clone, Debug, Default, Io, Encode, Decode)]
     pub struct Cpu {
-        load: Load,
+        pub load: Load,
     }
 
     impl Cpu {
@@ -136,11 +241,18 @@ mod cpu {
         }
     }
 
+    /// CPU frequency and load averages, all as plain numbers so they can be
+    /// exported to monitoring systems without re-parsing a debug string.
     #[derive(Clone, Debug, Default, Io, Encode, Decode)]
     pub struct Load {
-        frequency: String,
-        stats: String,
-        time: String,
+        /// Current CPU frequency, in Hz.
+        pub frequency_hz: u64,
+        /// 1-minute load average.
+        pub load_average_1: f64,
+        /// 5-minute load average.
+        pub load_average_5: f64,
+        /// 15-minute load average.
+        pub load_average_15: f64,
     }
 
     impl Load {
@@ -153,9 +265,17 @@ mod cpu {
         /// # Errors
         /// Can fail during computing metrics
         pub async fn calculate(&mut self) -> Result<()> {
-            self.frequency = format!("{:?}", cpu::frequency().await);
-            self.stats = format!("{:?}", cpu::stats().await);
-            self.time = format!("{:?}", cpu::time().await);
+            let frequency = cpu::frequency()
+                .await
+                .wrap_err("Failed to read cpu frequency")?;
+            self.frequency_hz = frequency.current().get::<hertz>();
+
+            let load_average = cpu::os::unix::loadavg()
+                .await
+                .wrap_err("Failed to read cpu load average")?;
+            self.load_average_1 = load_average.0;
+            self.load_average_5 = load_average.1;
+            self.load_average_15 = load_average.2;
             Ok(())
         }
     }
@@ -164,13 +284,20 @@ mod cpu {
 mod memory {
     use heim::memory;
     use iroha_derive::Io;
-    use iroha_error::Result;
+    use iroha_error::{Result, WrapErr};
     use parity_scale_codec::{Decode, Encode};
 
     #[derive(Clone, Debug, Default, Io, Encode, Decode)]
     pub struct Memory {
-        memory: String,
-        swap: String,
+        pub memory: MemoryUsage,
+        pub swap: MemoryUsage,
+    }
+
+    /// Total and currently used bytes for either RAM or swap.
+    #[derive(Clone, Copy, Debug, Default, Io, Encode, Decode)]
+    pub struct MemoryUsage {
+        pub total_bytes: u64,
+        pub used_bytes: u64,
     }
 
     impl Memory {
@@ -183,8 +310,13 @@ mod memory {
         /// # Errors
         /// Can fail during computing memory metrics
         pub async fn calculate(&mut self) -> Result<()> {
-            self.memory = format!("{:?}", memory::memory().await);
-            self.swap = format!("{:?}", memory::swap().await);
+            let memory = memory::memory().await.wrap_err("Failed to read memory")?;
+            self.memory.total_bytes = memory.total().value;
+            self.memory.used_bytes = memory.total().value - memory.available().value;
+
+            let swap = memory::swap().await.wrap_err("Failed to read swap")?;
+            self.swap.total_bytes = swap.total().value;
+            self.swap.used_bytes = swap.used().value;
             Ok(())
         }
     }
@@ -200,8 +332,7 @@ mod memory {
                 .calculate()
                 .await
                 .expect("Failed to calculate memory.");
-            assert!(!memory.memory.is_empty());
-            assert!(!memory.swap.is_empty());
+            assert!(memory.memory.total_bytes > 0);
         }
     }
-}
\ No newline at end of file
+}
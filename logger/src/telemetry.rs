@@ -2,14 +2,31 @@
 
 #![allow(clippy::module_name_repetitions)]
 
-use std::{error::Error, fmt::Debug};
+use std::{
+    collections::{HashSet, VecDeque},
+    error::Error,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::{Duration, Instant},
+};
 
 use derive_more::{Deref, DerefMut};
+use futures::{SinkExt, StreamExt};
 use serde_json::Value;
-use tokio::sync::mpsc::{self, Receiver, Sender};
+use tokio::{
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Notify,
+    },
+    task::JoinHandle,
+};
+use tokio_tungstenite::tungstenite::Message;
 use tracing::{
     field::{Field, Visit},
-    Event, Subscriber,
+    Event, Level, Subscriber,
 };
 
 use crate::layer::{EventInspectorTrait, EventSubscriber};
@@ -18,6 +35,9 @@ use crate::layer::{EventInspectorTrait, EventSubscriber};
 pub const TELEMETRY_TARGET_PREFIX: &str = "telemetry::";
 /// Target for telemetry future in `tracing`
 pub const TELEMETRY_FUTURE_TARGET_PREFIX: &str = "telemetry_future::";
+/// Name of the event field carrying a record's verbosity, as recorded by
+/// Substrate-style telemetry macros (e.g. `telemetry!(verbosity; ...)`).
+const VERBOSITY_FIELD: &str = "verbosity";
 
 /// Fields for telemetry (type for efficient saving)
 #[derive(Clone, Debug, PartialEq, Eq, Default, Deref, DerefMut)]
@@ -39,19 +59,35 @@ pub struct Telemetry {
     pub target: &'static str,
     /// Fields which was recorded
     pub fields: TelemetryFields,
+    /// Verbosity tag recorded on the event via a `verbosity` field, Substrate
+    /// style; defaults to `0` (most essential) when the event didn't record
+    /// one. Used by [`TelemetryLayer`] to decide which registered sinks a
+    /// record is forwarded to.
+    pub verbosity: u8,
 }
 
 impl Visit for Telemetry {
     fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == VERBOSITY_FIELD {
+            return;
+        }
         self.fields
             .push((field.name(), format!("{:?}", &value).into()))
     }
 
     fn record_i64(&mut self, field: &Field, value: i64) {
+        if field.name() == VERBOSITY_FIELD {
+            self.verbosity = u8::try_from(value).unwrap_or(if value < 0 { 0 } else { u8::MAX });
+            return;
+        }
         self.fields.push((field.name(), value.into()))
     }
 
     fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == VERBOSITY_FIELD {
+            self.verbosity = u8::try_from(value).unwrap_or(u8::MAX);
+            return;
+        }
         self.fields.push((field.name(), value.into()))
     }
 
@@ -76,31 +112,259 @@ impl Visit for Telemetry {
 impl Telemetry {
     fn from_event(target: &'static str, event: &Event<'_>) -> Self {
         let fields = TelemetryFields::default();
-        let mut telemetry = Self { target, fields };
+        let mut telemetry = Self {
+            target,
+            fields,
+            verbosity: 0,
+        };
         event.record(&mut telemetry);
         telemetry
     }
 }
 
+/// How many events must pass between synthetic `channel_overflow` telemetry
+/// emissions for the same channel, so a stuck receiver doesn't turn the
+/// records it's already dropping into a flood of overflow reports as well.
+const OVERFLOW_EMIT_INTERVAL: u64 = 1000;
+
+/// A batch of telemetry records sent over the channel as a unit, following
+/// the batching approach used by Stalwart's `trc` subscriber.
+pub type EventBatch = Vec<Telemetry>;
+
+/// Capacity, in batches, of each channel [`TelemetryLayer::with_batching`]
+/// creates; the batch size itself is the `batch_size` passed to it.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 1024;
+/// Hard upper bound on `max_batch_size`, regardless of what's requested.
+pub const MAX_BATCH_SIZE_CAP: usize = 32_768;
+
+/// Per-target buffer accumulating records between flushes.
+#[derive(Debug)]
+struct BatchBuffer {
+    records: Vec<Telemetry>,
+    last_flush: Instant,
+}
+
+impl BatchBuffer {
+    fn new() -> Self {
+        Self {
+            records: Vec::new(),
+            last_flush: Instant::now(),
+        }
+    }
+}
+
+/// How [`TelemetryLayer`] behaves when a telemetry channel is full.
+///
+/// Borrowed from the lossy/reliable distinction in Stalwart's `trc`
+/// subscriber: most deployments would rather lose an occasional telemetry
+/// record than have logging back-pressure the node, but some callers (e.g.
+/// compliance-sensitive telemetry sinks) need every record to arrive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryMode {
+    /// Drop the record on a full channel and keep going. Dropped counts are
+    /// tracked and exposed via [`TelemetryLayer::dropped_counts`].
+    Lossy,
+    /// Block the calling thread until the record fits, trading latency for
+    /// never losing a record. Requires a multi-threaded tokio runtime, since
+    /// delivery hands this thread's other tasks off to the rest of the pool
+    /// while it waits for channel capacity.
+    Reliable,
+}
+
+impl Default for DeliveryMode {
+    fn default() -> Self {
+        Self::Lossy
+    }
+}
+
+/// One registered receiver for a target, following Substrate's telemetry
+/// fan-out: it only receives records whose `verbosity` is at or below
+/// `max_level`.
+#[derive(Debug, Clone)]
+struct Sink {
+    sender: Sender<EventBatch>,
+    max_level: u8,
+    dropped: Arc<AtomicU64>,
+    since_overflow_emit: Arc<AtomicU64>,
+}
+
+impl Sink {
+    fn new(sender: Sender<EventBatch>, max_level: u8) -> Self {
+        Self {
+            sender,
+            max_level,
+            dropped: Arc::new(AtomicU64::new(0)),
+            since_overflow_emit: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+/// Result of checking a target against an [`InterestSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interest {
+    /// No targets are registered at all, so nothing downstream is watching
+    /// telemetry of this kind; treat the event as if it weren't telemetry.
+    NoneRegistered,
+    /// Targets are registered, but not this one.
+    NotInterested,
+    /// This target is registered.
+    Interested,
+}
+
+/// Adopts the `Interests` bitset idea from Stalwart's `trc` subscriber: a
+/// cheaply-cloneable, thread-safe set of target names that downstream
+/// telemetry receivers declare interest in, checked by [`TelemetryLayer`]
+/// before it does any field-visiting work for a record.
+#[derive(Debug, Clone, Default)]
+pub struct InterestSet {
+    targets: Arc<RwLock<HashSet<&'static str>>>,
+}
+
+impl InterestSet {
+    /// Declare interest in `target`, the bare name left after stripping
+    /// `telemetry::`/`telemetry_future::`.
+    pub fn register(&self, target: &'static str) {
+        #[allow(clippy::expect_used)]
+        self.targets
+            .write()
+            .expect("Interest set lock poisoned")
+            .insert(target);
+    }
+
+    /// Withdraw interest in `target`.
+    pub fn deregister(&self, target: &'static str) {
+        #[allow(clippy::expect_used)]
+        self.targets
+            .write()
+            .expect("Interest set lock poisoned")
+            .remove(target);
+    }
+
+    /// Replace the whole set with `targets`.
+    pub fn set(&self, targets: &[&'static str]) {
+        #[allow(clippy::expect_used)]
+        let mut guard = self.targets.write().expect("Interest set lock poisoned");
+        guard.clear();
+        guard.extend(targets.iter().copied());
+    }
+
+    fn interest_in(&self, target: &str) -> Interest {
+        #[allow(clippy::expect_used)]
+        let guard = self.targets.read().expect("Interest set lock poisoned");
+        if guard.is_empty() {
+            Interest::NoneRegistered
+        } else if guard.contains(target) {
+            Interest::Interested
+        } else {
+            Interest::NotInterested
+        }
+    }
+}
+
 /// Telemetry layer
 #[derive(Debug, Clone)]
 pub struct TelemetryLayer<S: Subscriber> {
-    telemetry_sender: Sender<Telemetry>,
-    telemetry_future_sender: Sender<Telemetry>,
+    regular_sinks: Vec<Sink>,
+    future_sinks: Vec<Sink>,
     subscriber: S,
+    mode: DeliveryMode,
+    max_batch_size: usize,
+    flush_interval: Duration,
+    regular_buffer: Arc<Mutex<BatchBuffer>>,
+    future_buffer: Arc<Mutex<BatchBuffer>>,
+    interests: InterestSet,
 }
 
 impl<S: Subscriber> TelemetryLayer<S> {
-    /// Create telemetry from channel sender
+    /// Create telemetry from a single channel sender per target, defaulting
+    /// to [`DeliveryMode::Lossy`] and no batching, for compatibility with
+    /// existing callers. The sender receives every record regardless of
+    /// verbosity, equivalent to registering it via [`Self::from_sinks`] with
+    /// `max_level` of [`u8::MAX`].
     pub fn from_senders(
         subscriber: S,
-        telemetry_sender: Sender<Telemetry>,
-        telemetry_future_sender: Sender<Telemetry>,
+        telemetry_sender: Sender<EventBatch>,
+        telemetry_future_sender: Sender<EventBatch>,
     ) -> impl Subscriber {
-        EventSubscriber(Self {
+        Self::from_senders_with_mode(
+            subscriber,
             telemetry_sender,
             telemetry_future_sender,
+            DeliveryMode::default(),
+        )
+    }
+
+    /// As [`Self::from_senders`], with an explicit [`DeliveryMode`].
+    pub fn from_senders_with_mode(
+        subscriber: S,
+        telemetry_sender: Sender<EventBatch>,
+        telemetry_future_sender: Sender<EventBatch>,
+        mode: DeliveryMode,
+    ) -> impl Subscriber {
+        Self::from_sinks_with_mode(
+            subscriber,
+            vec![(telemetry_sender, u8::MAX)],
+            vec![(telemetry_future_sender, u8::MAX)],
+            mode,
+        )
+    }
+
+    /// Register several `(sender, max_level)` pairs per target instead of a
+    /// single endpoint. Each record is only forwarded to the sinks whose
+    /// `max_level >= record.verbosity`, so e.g. a lightweight dashboard can
+    /// subscribe with a low `max_level` to take only the most essential
+    /// records while a verbose archival sink subscribes with
+    /// [`u8::MAX`] to take everything, without running two full subscriber
+    /// stacks.
+    pub fn from_sinks(
+        subscriber: S,
+        regular_sinks: Vec<(Sender<EventBatch>, u8)>,
+        future_sinks: Vec<(Sender<EventBatch>, u8)>,
+    ) -> impl Subscriber {
+        Self::from_sinks_with_mode(subscriber, regular_sinks, future_sinks, DeliveryMode::default())
+    }
+
+    /// As [`Self::from_sinks`], with an explicit [`DeliveryMode`].
+    pub fn from_sinks_with_mode(
+        subscriber: S,
+        regular_sinks: Vec<(Sender<EventBatch>, u8)>,
+        future_sinks: Vec<(Sender<EventBatch>, u8)>,
+        mode: DeliveryMode,
+    ) -> impl Subscriber {
+        Self::build(
             subscriber,
+            regular_sinks
+                .into_iter()
+                .map(|(sender, max_level)| Sink::new(sender, max_level))
+                .collect(),
+            future_sinks
+                .into_iter()
+                .map(|(sender, max_level)| Sink::new(sender, max_level))
+                .collect(),
+            mode,
+            1,
+            Duration::default(),
+        )
+    }
+
+    fn build(
+        subscriber: S,
+        regular_sinks: Vec<Sink>,
+        future_sinks: Vec<Sink>,
+        mode: DeliveryMode,
+        max_batch_size: usize,
+        flush_interval: Duration,
+    ) -> impl Subscriber {
+        EventSubscriber(Self {
+            regular_sinks,
+            future_sinks,
+            subscriber,
+            mode,
+            max_batch_size: max_batch_size.clamp(1, MAX_BATCH_SIZE_CAP),
+            flush_interval,
+            regular_buffer: Arc::new(Mutex::new(BatchBuffer::new())),
+            future_buffer: Arc::new(Mutex::new(BatchBuffer::new())),
+            interests: InterestSet::default(),
         })
     }
 
@@ -108,10 +372,20 @@ impl<S: Subscriber> TelemetryLayer<S> {
     #[allow(clippy::new_ret_no_self)]
     pub fn new<const CHANNEL_SIZE: usize>(
         subscriber: S,
-    ) -> (impl Subscriber, Receiver<Telemetry>, Receiver<Telemetry>) {
+    ) -> (impl Subscriber, Receiver<EventBatch>, Receiver<EventBatch>) {
+        Self::new_with_mode::<CHANNEL_SIZE>(subscriber, DeliveryMode::default())
+    }
+
+    /// Create new telemetry layer with specific channel size (via const
+    /// generic) and an explicit [`DeliveryMode`]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new_with_mode<const CHANNEL_SIZE: usize>(
+        subscriber: S,
+        mode: DeliveryMode,
+    ) -> (impl Subscriber, Receiver<EventBatch>, Receiver<EventBatch>) {
         let (sender, receiver) = mpsc::channel(CHANNEL_SIZE);
         let (sender_future, receiver_future) = mpsc::channel(CHANNEL_SIZE);
-        let telemetry = Self::from_senders(subscriber, sender, sender_future);
+        let telemetry = Self::from_senders_with_mode(subscriber, sender, sender_future, mode);
         (telemetry, receiver, receiver_future)
     }
 
@@ -120,12 +394,188 @@ impl<S: Subscriber> TelemetryLayer<S> {
     pub fn from_capacity(
         subscriber: S,
         channel_size: usize,
-    ) -> (impl Subscriber, Receiver<Telemetry>, Receiver<Telemetry>) {
+    ) -> (impl Subscriber, Receiver<EventBatch>, Receiver<EventBatch>) {
+        Self::from_capacity_with_mode(subscriber, channel_size, DeliveryMode::default())
+    }
+
+    /// Create new telemetry layer with specific channel size and an
+    /// explicit [`DeliveryMode`]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn from_capacity_with_mode(
+        subscriber: S,
+        channel_size: usize,
+        mode: DeliveryMode,
+    ) -> (impl Subscriber, Receiver<EventBatch>, Receiver<EventBatch>) {
         let (sender, receiver) = mpsc::channel(channel_size);
         let (sender_future, receiver_future) = mpsc::channel(channel_size);
-        let telemetry = Self::from_senders(subscriber, sender, sender_future);
+        let telemetry = Self::from_senders_with_mode(subscriber, sender, sender_future, mode);
+        (telemetry, receiver, receiver_future)
+    }
+
+    /// Create a new telemetry layer that accumulates up to `batch_size`
+    /// records (clamped to [`MAX_BATCH_SIZE_CAP`], with `0` treated as `1`)
+    /// per target before flushing, or flushes early once `flush_interval`
+    /// has elapsed since the last flush. A `batch_size` of `1` falls back to
+    /// sending each record immediately, matching pre-batching semantics.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn with_batching(
+        subscriber: S,
+        batch_size: usize,
+        flush_interval: Duration,
+    ) -> (impl Subscriber, Receiver<EventBatch>, Receiver<EventBatch>) {
+        let (sender, receiver) = mpsc::channel(DEFAULT_MAX_BATCH_SIZE);
+        let (sender_future, receiver_future) = mpsc::channel(DEFAULT_MAX_BATCH_SIZE);
+        let telemetry = Self::build(
+            subscriber,
+            vec![Sink::new(sender, u8::MAX)],
+            vec![Sink::new(sender_future, u8::MAX)],
+            DeliveryMode::default(),
+            batch_size,
+            flush_interval,
+        );
         (telemetry, receiver, receiver_future)
     }
+
+    /// Number of telemetry records dropped so far per registered sink, in
+    /// registration order, as `(regular, future)`.
+    ///
+    /// Only grows in [`DeliveryMode::Lossy`]; in [`DeliveryMode::Reliable`]
+    /// sends block instead of dropping, so these stay at zero unless the
+    /// receiver itself has been dropped.
+    pub fn dropped_counts(&self) -> (Vec<u64>, Vec<u64>) {
+        (
+            self.regular_sinks
+                .iter()
+                .map(|sink| sink.dropped.load(Ordering::Relaxed))
+                .collect(),
+            self.future_sinks
+                .iter()
+                .map(|sink| sink.dropped.load(Ordering::Relaxed))
+                .collect(),
+        )
+    }
+
+    /// Replace the set of targets any downstream receiver cares about.
+    /// `event()` consults this (after the cheap `strip_prefix` check) before
+    /// doing any field-visiting work, so targets nobody registered interest
+    /// in are skipped entirely rather than allocated and then dropped.
+    pub fn set_interests(&self, targets: &[&'static str]) {
+        self.interests.set(targets);
+    }
+
+    /// A cloneable handle onto this layer's interest set, for receivers to
+    /// register or deregister individual targets independently of whatever
+    /// else is calling [`Self::set_interests`].
+    pub fn interest_handle(&self) -> InterestSet {
+        self.interests.clone()
+    }
+
+    /// Buffer `telemetry`, flushing its target's batch once it reaches
+    /// `max_batch_size` or `flush_interval` has elapsed since the last
+    /// flush. With `max_batch_size == 1` the record is dispatched
+    /// immediately.
+    fn buffer_and_maybe_flush(&self, buffer: &Mutex<BatchBuffer>, sinks: &[Sink], telemetry: Telemetry) {
+        if self.max_batch_size <= 1 {
+            self.dispatch(sinks, vec![telemetry]);
+            return;
+        }
+
+        #[allow(clippy::expect_used)]
+        let batch = {
+            let mut buffer = buffer.lock().expect("Telemetry batch buffer mutex poisoned");
+            buffer.records.push(telemetry);
+            let should_flush = buffer.records.len() >= self.max_batch_size
+                || buffer.last_flush.elapsed() >= self.flush_interval;
+            should_flush.then(|| {
+                buffer.last_flush = Instant::now();
+                std::mem::take(&mut buffer.records)
+            })
+        };
+
+        if let Some(batch) = batch {
+            self.dispatch(sinks, batch);
+        }
+    }
+
+    /// Flush whatever is left in `buffer`, e.g. on layer drop. A no-op if
+    /// the buffer is already empty, so it's safe to call from every clone
+    /// sharing the same buffer.
+    fn flush_buffer(&self, buffer: &Mutex<BatchBuffer>, sinks: &[Sink]) {
+        #[allow(clippy::expect_used)]
+        let batch =
+            std::mem::take(&mut buffer.lock().expect("Telemetry batch buffer mutex poisoned").records);
+        if !batch.is_empty() {
+            self.dispatch(sinks, batch);
+        }
+    }
+
+    /// Forward `batch` to every sink whose `max_level` covers the
+    /// verbosity of at least one record in it, each getting only the subset
+    /// of records it's eligible for.
+    fn dispatch(&self, sinks: &[Sink], batch: EventBatch) {
+        if batch.is_empty() {
+            return;
+        }
+        for sink in sinks {
+            let filtered: EventBatch = batch
+                .iter()
+                .filter(|telemetry| telemetry.verbosity <= sink.max_level)
+                .cloned()
+                .collect();
+            if !filtered.is_empty() {
+                self.send_to_sink(sink, filtered);
+            }
+        }
+    }
+
+    fn send_to_sink(&self, sink: &Sink, batch: EventBatch) {
+        #[allow(clippy::cast_possible_truncation)]
+        let batch_len = batch.len() as u64;
+        match self.mode {
+            DeliveryMode::Lossy => {
+                if sink.sender.try_send(batch).is_err() {
+                    sink.dropped.fetch_add(batch_len, Ordering::Relaxed);
+                    if sink.since_overflow_emit.fetch_add(batch_len, Ordering::Relaxed)
+                        >= OVERFLOW_EMIT_INTERVAL
+                    {
+                        sink.since_overflow_emit.store(0, Ordering::Relaxed);
+                        // Best-effort: if the channel is still full this is
+                        // itself silently dropped rather than retried, so a
+                        // persistently stuck receiver can't make us recurse.
+                        let _result = sink.sender.try_send(vec![Telemetry {
+                            target: "channel_overflow",
+                            fields: TelemetryFields(vec![(
+                                "dropped",
+                                sink.dropped.load(Ordering::Relaxed).into(),
+                            )]),
+                            verbosity: 0,
+                        }]);
+                    }
+                }
+            }
+            DeliveryMode::Reliable => {
+                // `event()` is a synchronous callback that `tracing` may
+                // invoke from inside an async task running on a tokio
+                // worker thread, where `Sender::blocking_send` panics.
+                // `block_in_place` hands this thread's other tasks off to
+                // the rest of the pool before we block it on the send, so
+                // the wait for channel capacity doesn't panic there either.
+                let result = tokio::task::block_in_place(|| {
+                    tokio::runtime::Handle::current().block_on(sink.sender.send(batch))
+                });
+                if result.is_err() {
+                    sink.dropped.fetch_add(batch_len, Ordering::Relaxed);
+                }
+            }
+        }
+    }
+}
+
+impl<S: Subscriber> Drop for TelemetryLayer<S> {
+    fn drop(&mut self) {
+        self.flush_buffer(&self.regular_buffer, &self.regular_sinks);
+        self.flush_buffer(&self.future_buffer, &self.future_sinks);
+    }
 }
 
 impl<S: Subscriber> EventInspectorTrait for TelemetryLayer<S> {
@@ -139,15 +589,190 @@ impl<S: Subscriber> EventInspectorTrait for TelemetryLayer<S> {
         let target = event.metadata().target();
         #[allow(clippy::option_if_let_else)] // This is actually more readable.
         if let Some(telemetry_target) = target.strip_prefix(TELEMETRY_TARGET_PREFIX) {
-            let _result = self
-                .telemetry_sender
-                .try_send(Telemetry::from_event(telemetry_target, event));
+            match self.interests.interest_in(telemetry_target) {
+                // Nobody has registered any interest at all: treat telemetry
+                // as unconfigured and fall through to the inner subscriber.
+                Interest::NoneRegistered => self.subscriber.event(event),
+                // Somebody is listening, just not to this target: drop it
+                // without visiting a single field.
+                Interest::NotInterested => {}
+                Interest::Interested => self.buffer_and_maybe_flush(
+                    &self.regular_buffer,
+                    &self.regular_sinks,
+                    Telemetry::from_event(telemetry_target, event),
+                ),
+            }
         } else if let Some(future_target) = target.strip_prefix(TELEMETRY_FUTURE_TARGET_PREFIX) {
-            let _result = self
-                .telemetry_future_sender
-                .try_send(Telemetry::from_event(future_target, event));
+            match self.interests.interest_in(future_target) {
+                Interest::NoneRegistered => self.subscriber.event(event),
+                Interest::NotInterested => {}
+                Interest::Interested => self.buffer_and_maybe_flush(
+                    &self.future_buffer,
+                    &self.future_sinks,
+                    Telemetry::from_event(future_target, event),
+                ),
+            }
         } else {
             self.subscriber.event(event)
         }
     }
 }
+
+impl From<Telemetry> for Value {
+    fn from(telemetry: Telemetry) -> Self {
+        let mut value = Value::from(telemetry.fields);
+        if let Self::Object(ref mut map) = value {
+            map.insert("target".to_owned(), telemetry.target.into());
+            map.insert("verbosity".to_owned(), telemetry.verbosity.into());
+        }
+        value
+    }
+}
+
+/// Capacity of a single endpoint's outbound queue, in messages. Sized
+/// generously for small JSON payloads; once full, [`OutboundQueue::push`]
+/// drops the oldest queued message to make room for the newest rather than
+/// ever applying backpressure to whoever is pushing.
+const OUTBOUND_QUEUE_CAPACITY: usize = 4096;
+
+/// Delay before the first reconnect attempt to an endpoint, doubled after
+/// each consecutive failure up to [`MAX_RECONNECT_BACKOFF`].
+const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_millis(500);
+/// Ceiling on the reconnect backoff applied between attempts.
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Target telemetry records describing a remote endpoint's own connection
+/// state are emitted under, so operators can see collector connectivity
+/// alongside everything else a node reports.
+const TELEMETRY_REMOTE_WORKER_TARGET: &str = "telemetry::remote_worker";
+
+/// Connection state of a single remote telemetry endpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EndpointState {
+    Connecting,
+    Connected,
+    Disconnected,
+}
+
+impl EndpointState {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Connecting => "connecting",
+            Self::Connected => "connected",
+            Self::Disconnected => "disconnected",
+        }
+    }
+}
+
+/// A bounded, drop-oldest FIFO of pending outbound messages for a single
+/// endpoint. Decoupling the queue from the connection lets the dispatch
+/// loop keep accepting new telemetry while a connection is down or
+/// backing off, without ever blocking on it.
+#[derive(Debug, Default)]
+struct OutboundQueue {
+    messages: Mutex<VecDeque<Value>>,
+    notify: Notify,
+}
+
+impl OutboundQueue {
+    fn push(&self, message: Value) {
+        #[allow(clippy::expect_used)]
+        let mut messages = self.messages.lock().expect("outbound queue lock poisoned");
+        if messages.len() >= OUTBOUND_QUEUE_CAPACITY {
+            messages.pop_front();
+        }
+        messages.push_back(message);
+        drop(messages);
+        self.notify.notify_one();
+    }
+
+    async fn pop(&self) -> Value {
+        loop {
+            #[allow(clippy::expect_used)]
+            {
+                let mut messages = self.messages.lock().expect("outbound queue lock poisoned");
+                if let Some(message) = messages.pop_front() {
+                    return message;
+                }
+            }
+            self.notify.notified().await;
+        }
+    }
+}
+
+/// Ships telemetry records emitted on this node to one or more remote
+/// collectors over `WebSocket`, in the spirit of Substrate's
+/// `sc-telemetry` worker. All network I/O — connecting, reconnecting with
+/// backoff, sending — happens on tasks owned by this worker, so a slow or
+/// unreachable collector never stalls the tracing hot path that feeds it.
+#[derive(Debug)]
+pub struct TelemetryWorker;
+
+impl TelemetryWorker {
+    /// Drain `receiver` and forward every record, serialized to JSON, to
+    /// each of `endpoints`. Each endpoint gets its own connection, its own
+    /// reconnect backoff and its own [`OutboundQueue`], so a broken
+    /// collector only ever starves its own queue, never its siblings'.
+    pub fn spawn(mut receiver: Receiver<EventBatch>, endpoints: Vec<String>) -> JoinHandle<()> {
+        let queues: Vec<_> = endpoints
+            .into_iter()
+            .map(|endpoint| {
+                let queue = Arc::new(OutboundQueue::default());
+                tokio::spawn(Self::run_endpoint(endpoint, Arc::clone(&queue)));
+                queue
+            })
+            .collect();
+
+        tokio::spawn(async move {
+            while let Some(batch) = receiver.recv().await {
+                for telemetry in batch {
+                    let message = Value::from(telemetry);
+                    for queue in &queues {
+                        queue.push(message.clone());
+                    }
+                }
+            }
+        })
+    }
+
+    /// Own a single endpoint's connection for the lifetime of the worker:
+    /// connect, drain its queue onto the socket until the connection
+    /// fails, then back off and reconnect, forever.
+    async fn run_endpoint(endpoint: String, queue: Arc<OutboundQueue>) {
+        let mut backoff = INITIAL_RECONNECT_BACKOFF;
+        loop {
+            Self::emit_connection_state(&endpoint, EndpointState::Connecting);
+            match tokio_tungstenite::connect_async(&endpoint).await {
+                Ok((stream, _response)) => {
+                    backoff = INITIAL_RECONNECT_BACKOFF;
+                    Self::emit_connection_state(&endpoint, EndpointState::Connected);
+                    let (mut sink, _stream) = stream.split();
+                    loop {
+                        let message = queue.pop().await;
+                        let Ok(text) = serde_json::to_string(&message) else {
+                            continue;
+                        };
+                        if sink.send(Message::Text(text)).await.is_err() {
+                            break;
+                        }
+                    }
+                }
+                Err(error) => {
+                    tracing::warn!(%endpoint, %error, "Failed to connect to remote telemetry endpoint");
+                }
+            }
+            Self::emit_connection_state(&endpoint, EndpointState::Disconnected);
+            tokio::time::sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+        }
+    }
+
+    fn emit_connection_state(endpoint: &str, state: EndpointState) {
+        tracing::event!(
+            target: TELEMETRY_REMOTE_WORKER_TARGET,
+            Level::INFO,
+            endpoint,
+            state = state.as_str(),
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Declaratively assembling a [`Validator`](crate::validator::Validator)
+//! from a set of checks instead of hand-writing the `From`/`IsAllowed`
+//! plumbing the `impl_from_item_for_*` macros hide.
+
+use crate::validator::Validator;
+use super::*;
+
+/// How a [`ValidatorBuilder`]'s checks combine into one pass/fail result.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum Policy {
+    /// Every check must allow the instruction/query.
+    All,
+    /// At least one check must allow the instruction/query.
+    Any,
+}
+
+struct Combined<T> {
+    checks: Vec<T>,
+    policy: Policy,
+}
+
+impl<T> Serialize for Combined<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        // The checks themselves are boxed trait objects with no `Serialize`
+        // bound, so only the combination policy and check count are surfaced.
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("Combined", 2)?;
+        state.serialize_field("policy", &self.policy)?;
+        state.serialize_field("check_count", &self.checks.len())?;
+        state.end()
+    }
+}
+
+impl IsAllowed<Instruction> for Combined<IsInstructionAllowedBoxed> {
+    fn check(&self, authority: &AccountId, instruction: &Instruction, wsv: &WorldStateView) -> Result<()> {
+        match self.policy {
+            Policy::All => {
+                if self.checks.is_empty() {
+                    return Err("No instruction checks configured: denying by default.".into());
+                }
+                for check in &self.checks {
+                    check.check(authority, instruction, wsv)?;
+                }
+                Ok(())
+            }
+            Policy::Any => {
+                let mut reasons = Vec::new();
+                for check in &self.checks {
+                    match check.check(authority, instruction, wsv) {
+                        Ok(()) => return Ok(()),
+                        Err(reason) => reasons.push(reason.to_string()),
+                    }
+                }
+                Err(format!("No check allowed this instruction:\n{}", reasons.join("\n")).into())
+            }
+        }
+    }
+}
+
+impl IsAllowed<QueryBox> for Combined<IsQueryAllowedBoxed> {
+    fn check(&self, authority: &AccountId, query: &QueryBox, wsv: &WorldStateView) -> Result<()> {
+        match self.policy {
+            Policy::All => {
+                if self.checks.is_empty() {
+                    return Err("No query checks configured: denying by default.".into());
+                }
+                for check in &self.checks {
+                    check.check(authority, query, wsv)?;
+                }
+                Ok(())
+            }
+            Policy::Any => {
+                let mut reasons = Vec::new();
+                for check in &self.checks {
+                    match check.check(authority, query, wsv) {
+                        Ok(()) => return Ok(()),
+                        Err(reason) => reasons.push(reason.to_string()),
+                    }
+                }
+                Err(format!("No check allowed this query:\n{}", reasons.join("\n")).into())
+            }
+        }
+    }
+}
+
+/// Requires the authority granting/revoking a [`PermissionToken`] to
+/// already hold a token with the same name as the one being granted or
+/// revoked - derived from a [`PermissionTokenSchema`] so a
+/// [`ValidatorBuilder`] user gets it for free instead of writing a
+/// per-token `IsGrantAllowed`/`IsRevokeAllowed` impl just to enforce "you
+/// can't hand out permissions you don't hold yourself".
+#[derive(Debug, Copy, Clone, Serialize)]
+struct RequiresHoldingToken;
+
+impl_from_item_for_grant_instruction_validator_box!(RequiresHoldingToken);
+
+// Not `impl_from_item_for_revoke_instruction_validator_box!`: that macro
+// also generates `From<RequiresHoldingToken> for IsInstructionAllowedBoxed`,
+// which the grant macro above already provides - invoking both would be a
+// conflicting trait impl. `RequiresHoldingToken` only ever needs boxing as
+// an instruction check here, so the revoke-specific box conversion alone
+// is written out by hand instead.
+impl From<RequiresHoldingToken> for IsRevokeAllowedBoxed {
+    fn from(validator: RequiresHoldingToken) -> Self {
+        Box::new(validator)
+    }
+}
+
+fn authority_holds_token_named(
+    authority: &AccountId,
+    name: &Name,
+    wsv: &WorldStateView,
+) -> Result<bool> {
+    Ok(wsv
+        .map_account(authority, |account| wsv.account_permission_tokens(account))
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .any(|token| token.name() == name))
+}
+
+impl IsGrantAllowed for RequiresHoldingToken {
+    fn check(&self, authority: &AccountId, instruction: &GrantBox, wsv: &WorldStateView) -> Result<()> {
+        let token: iroha_data_model::permissions::PermissionToken =
+            extract_specialized_token(instruction, wsv)?;
+
+        if authority_holds_token_named(authority, token.name(), wsv)? {
+            Ok(())
+        } else {
+            Err(format!(
+                "Granting `{}` requires the granting account to already hold it.",
+                token.name()
+            )
+            .into())
+        }
+    }
+}
+
+impl IsRevokeAllowed for RequiresHoldingToken {
+    fn check(&self, authority: &AccountId, instruction: &RevokeBox, wsv: &WorldStateView) -> Result<()> {
+        let token: iroha_data_model::permissions::PermissionToken =
+            extract_specialized_token(instruction, wsv)?;
+
+        if authority_holds_token_named(authority, token.name(), wsv)? {
+            Ok(())
+        } else {
+            Err(format!(
+                "Revoking `{}` requires the revoking account to hold it.",
+                token.name()
+            )
+            .into())
+        }
+    }
+}
+
+/// Assembles a [`Validator`] from any mix of this crate's predefined
+/// [`HasTokenBoxed`], [`IsGrantAllowedBoxed`], [`IsRevokeAllowedBoxed`] and
+/// [`IsQueryAllowedBoxed`] checks, combined with a [`Policy`], so a
+/// deployment declares its tokens and checks once and gets a coherent
+/// [`Validator`] back instead of hand-composing `impl_from_item_for_*`
+/// conversions.
+#[derive(Default)]
+pub struct ValidatorBuilder {
+    instruction_checks: Vec<IsInstructionAllowedBoxed>,
+    query_checks: Vec<IsQueryAllowedBoxed>,
+}
+
+impl ValidatorBuilder {
+    /// An empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an instruction-validating check - any of [`HasTokenBoxed`],
+    /// [`IsGrantAllowedBoxed`] or [`IsRevokeAllowedBoxed`] converts into
+    /// [`IsInstructionAllowedBoxed`] already via the `impl_from_item_for_*`
+    /// macros, so this accepts anything that does.
+    #[must_use]
+    pub fn with_instruction_check(mut self, check: impl Into<IsInstructionAllowedBoxed>) -> Self {
+        self.instruction_checks.push(check.into());
+        self
+    }
+
+    /// Add a query-validating check.
+    #[must_use]
+    pub fn with_query_check(mut self, check: impl Into<IsQueryAllowedBoxed>) -> Self {
+        self.query_checks.push(check.into());
+        self
+    }
+
+    /// Add the schema-driven checks derived from `schema`: that a granted
+    /// token's name and parameters match `schema` (see
+    /// [`PermissionTokenSchema::allows`]), and that granting/revoking a
+    /// token requires the authority to already hold one by that name.
+    ///
+    /// These checks are combined with an internal, non-overridable
+    /// [`Policy::All`] before being added, rather than pushed separately
+    /// into the builder's own check list: pushed separately, a caller
+    /// building with [`Policy::Any`] could grant any token that merely
+    /// matches the schema, without the authority holding it first, since
+    /// either check passing would be enough. Requiring a token to be
+    /// grantable at all must never be satisfiable on its own.
+    #[must_use]
+    pub fn with_schema(mut self, schema: PermissionTokenSchema) -> Self {
+        let schema_check: IsInstructionAllowedBoxed = Box::new(Combined {
+            checks: vec![
+                TokenMatchesSchema(schema).into(),
+                RequiresHoldingToken.into(),
+                {
+                    let revoke_check: IsRevokeAllowedBoxed = RequiresHoldingToken.into();
+                    let revoke_check: IsInstructionAllowedBoxed = Box::new(revoke_check);
+                    revoke_check
+                },
+            ],
+            policy: Policy::All,
+        });
+        self.instruction_checks.push(schema_check);
+        self
+    }
+
+    /// Build the final [`Validator`], combining every added check with
+    /// `policy`.
+    #[must_use]
+    pub fn build(self, policy: Policy) -> Validator {
+        let instruction: IsInstructionAllowedBoxed = Box::new(Combined {
+            checks: self.instruction_checks,
+            policy,
+        });
+        let query: IsQueryAllowedBoxed = Box::new(Combined {
+            checks: self.query_checks,
+            policy,
+        });
+
+        Validator::new(instruction, query)
+    }
+}
+
+/// Like [`GrantedTokenMatchesSchema`], but checked against a specific
+/// `schema` instead of always [`PermissionTokenSchema::default`] - used by
+/// [`ValidatorBuilder::with_schema`] so a custom token set can be checked
+/// without needing to appear in `PermissionTokenSchema::default`.
+#[derive(Debug, Clone, Serialize)]
+struct TokenMatchesSchema(PermissionTokenSchema);
+
+impl_from_item_for_grant_instruction_validator_box!(TokenMatchesSchema);
+
+impl IsGrantAllowed for TokenMatchesSchema {
+    fn check(&self, _authority: &AccountId, instruction: &GrantBox, wsv: &WorldStateView) -> Result<()> {
+        let token: iroha_data_model::permissions::PermissionToken =
+            extract_specialized_token(instruction, wsv)?;
+
+        if self.0.allows(&token) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Permission token `{}` is unknown or its parameters don't match the schema.",
+                token.name()
+            )
+            .into())
+        }
+    }
+}
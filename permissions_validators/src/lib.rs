@@ -96,6 +96,162 @@ macro_rules! try_into_or_exit {
     };
 }
 
+/// Coarse tag for the kind of [`Value`](iroha_data_model::Value) a
+/// `declare_token!`-generated permission token's parameter holds.
+/// [`PermissionTokenSchema`] uses this (rather than the parameter's
+/// concrete Rust type, which isn't available once a token's been
+/// type-erased into a generic
+/// [`PermissionToken`](iroha_data_model::permissions::PermissionToken))
+/// to check that a stored token's parameters still match what the token
+/// was declared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ValueType {
+    /// An identifier, e.g. an `AssetId`/`AccountId`/`DomainId`.
+    Id,
+    /// A string-encoded value.
+    String,
+    /// A `u128`-encoded value.
+    U128,
+}
+
+/// Implemented by every type usable as a `declare_token!` parameter, so
+/// the macro can record each parameter's [`ValueType`] in the token's
+/// generated [`PermissionTokenDefinition`] without requiring callers to
+/// spell it out by hand at every declaration site.
+pub trait HasValueType {
+    /// This type's [`ValueType`] tag.
+    fn value_type() -> ValueType;
+}
+
+/// One parameter of a [`PermissionTokenDefinition`]: its name, and the
+/// [`ValueType`] a stored [`PermissionToken`](iroha_data_model::permissions::PermissionToken)'s
+/// value for it must match.
+pub type PermissionTokenParameter = (Name, ValueType);
+
+/// A single `declare_token!`-declared token's shape: its name and the
+/// ordered parameters it carries. Produced by each token's generated
+/// `schema_entry()` and collected into a [`PermissionTokenSchema`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PermissionTokenDefinition {
+    name: Name,
+    params: Vec<PermissionTokenParameter>,
+}
+
+/// The set of every permission token this deployment understands, along
+/// with its declared parameter names and types. [`Default`] returns every
+/// token declared via `declare_token!` in [`private_blockchain`] and
+/// [`public_blockchain`], giving operators a single source of truth for
+/// what a valid [`PermissionToken`](iroha_data_model::permissions::PermissionToken) looks like.
+#[derive(Debug, Clone, Serialize)]
+pub struct PermissionTokenSchema {
+    definitions: Vec<PermissionTokenDefinition>,
+}
+
+impl Default for PermissionTokenSchema {
+    fn default() -> Self {
+        use public_blockchain::{domain, key_value};
+
+        #[allow(deprecated)]
+        Self {
+            definitions: vec![
+                key_value::CanSetKeyValueInUserAssets::schema_entry(),
+                key_value::CanRemoveKeyValueInUserAssets::schema_entry(),
+                key_value::CanSetKeyValueInUserMetadata::schema_entry(),
+                key_value::CanRemoveKeyValueInUserMetadata::schema_entry(),
+                key_value::CanSetKeyValueInAssetDefinition::schema_entry(),
+                key_value::CanRemoveKeyValueInAssetDefinition::schema_entry(),
+                key_value::CanModifyUserAssetsMetadata::schema_entry(),
+                key_value::CanModifyAccountMetadata::schema_entry(),
+                key_value::CanModifyAssetDefinitionMetadata::schema_entry(),
+                key_value::CanModifyAllAssetsMetadata::schema_entry(),
+                key_value::CanModifyAllAssetDefinitionsMetadata::schema_entry(),
+                domain::CanSetDomainMetadataLimits::schema_entry(),
+            ],
+        }
+    }
+}
+
+/// A [`PermissionTokenSchema`] reduced to a name/parameter-shape snapshot,
+/// suitable for persisting alongside a release and diffing against a later
+/// one - see [`migration`].
+pub type PermissionTokenSchemaSnapshot = BTreeMap<Name, Vec<PermissionTokenParameter>>;
+
+impl PermissionTokenSchema {
+    /// This schema's [`PermissionTokenSchemaSnapshot`].
+    #[must_use]
+    pub fn as_snapshot(&self) -> PermissionTokenSchemaSnapshot {
+        self.definitions
+            .iter()
+            .map(|definition| (definition.name.clone(), definition.params.clone()))
+            .collect()
+    }
+
+    /// Whether `token` has a name known to this schema and parameters
+    /// matching the declared names and types exactly (same count, same
+    /// names, same [`ValueType`]s - order doesn't matter).
+    #[must_use]
+    pub fn allows(&self, token: &iroha_data_model::permissions::PermissionToken) -> bool {
+        let Some(definition) = self.definitions.iter().find(|d| &d.name == token.name()) else {
+            return false;
+        };
+
+        let params: Vec<_> = token.params().collect();
+        if params.len() != definition.params.len() {
+            return false;
+        }
+
+        definition.params.iter().all(|(name, value_type)| {
+            params.iter().any(|(param_name, value)| {
+                *param_name == name && value_types_match(value, *value_type)
+            })
+        })
+    }
+}
+
+/// Whether `value`'s runtime shape matches the declared `value_type`.
+fn value_types_match(value: &Value, value_type: ValueType) -> bool {
+    match (value, value_type) {
+        (Value::String(_), ValueType::String) => true,
+        (Value::U128(_), ValueType::U128) => true,
+        (Value::Id(_), ValueType::Id) => true,
+        _ => false,
+    }
+}
+
+/// Checks a `Grant` instruction's [`PermissionToken`](iroha_data_model::permissions::PermissionToken)
+/// against a [`PermissionTokenSchema`], rejecting any token whose name
+/// isn't declared or whose parameters don't match what was declared for
+/// that name. Without this, an unknown or malformed token (a typo in its
+/// name, a missing or mistyped parameter) would otherwise pass
+/// `try_into_or_exit!` silently and end up stored as a grant no validator
+/// can ever actually honour.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantedTokenMatchesSchema;
+
+impl_from_item_for_grant_instruction_validator_box!(GrantedTokenMatchesSchema);
+
+impl IsGrantAllowed for GrantedTokenMatchesSchema {
+    fn check(
+        &self,
+        _authority: &AccountId,
+        instruction: &GrantBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: iroha_data_model::permissions::PermissionToken =
+            extract_specialized_token(instruction, wsv)?;
+
+        if !PermissionTokenSchema::default().allows(&token) {
+            return Err(format!(
+                "Permission token `{}` is unknown or its parameters don't match the schema.",
+                token.name()
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
 macro_rules! declare_token {
     (
         $(#[$outer_meta:meta])* // Structure attributes
@@ -123,7 +279,7 @@ macro_rules! declare_token {
             iroha_schema::IntoSchema,
         )]
         pub struct $ident
-        where $($param_typ: Into<Value>,)* {
+        where $($param_typ: Into<Value> + HasValueType,)* {
             $(
                 $(#[$inner_meta])*
                 #[doc = concat!(
@@ -160,6 +316,18 @@ macro_rules! declare_token {
                     $($param_name,)*
                 }
             }
+
+            /// This token's [`PermissionTokenDefinition`], for registering
+            /// it into a [`PermissionTokenSchema`].
+            #[allow(unused)] // `params` can be empty for tokens with no parameters
+            pub fn schema_entry() -> PermissionTokenDefinition {
+                PermissionTokenDefinition {
+                    name: Self::name().clone(),
+                    params: vec![
+                        $((Self::$param_name().clone(), <$param_typ>::value_type())),*
+                    ],
+                }
+            }
         }
 
         impl From<$ident> for iroha_data_model::permissions::PermissionToken {
@@ -213,5 +381,8 @@ pub enum PredefinedTokenConversionError {
 }
 
 // I need to put these modules after the macro definitions.
+pub mod builder;
+pub mod migration;
 pub mod private_blockchain;
 pub mod public_blockchain;
+pub mod validator;
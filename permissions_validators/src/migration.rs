@@ -0,0 +1,86 @@
+//! Retiring permission tokens between releases.
+//!
+//! When a token type is dropped from [`public_blockchain`](crate::public_blockchain)
+//! or [`private_blockchain`](crate::private_blockchain), accounts and roles
+//! that were granted it keep a [`PermissionToken`] no validator can ever
+//! honour again. [`revoke_obsolete_tokens`] diffs the schema snapshot a peer
+//! persisted at its last upgrade against its current [`PermissionTokenSchema`]
+//! and reports what needs cleaning up: directly-granted tokens become
+//! [`RevokeBox`] instructions, while role-held tokens are only reported,
+//! since there's no instruction in this tree to drop a single permission
+//! from an existing [`Role`] - a role would need to be unregistered and
+//! re-registered without the offending token.
+use std::collections::BTreeSet;
+
+use iroha_core::wsv::WorldStateView;
+use iroha_data_model::prelude::*;
+
+use crate::PermissionTokenSchemaSnapshot;
+
+/// The result of diffing a previous [`PermissionTokenSchemaSnapshot`]
+/// against the current one and walking every account and role for tokens
+/// whose name was removed.
+#[derive(Debug, Default)]
+pub struct ObsoleteTokens {
+    /// `RevokeBox` instructions that retire a directly-granted obsolete
+    /// token from the account holding it.
+    pub revocations: Vec<RevokeBox>,
+    /// Roles that still carry an obsolete token among their own permissions
+    /// and so need to be redefined without it; this tree has no instruction
+    /// to drop a single permission from a registered [`Role`].
+    pub affected_roles: Vec<<Role as Identifiable>::Id>,
+}
+
+/// Diffs `previous` against `current` and collects the [`ObsoleteTokens`]
+/// needed to retire every stored [`PermissionToken`] whose name is present
+/// in `previous` but absent from `current` - i.e. every token type
+/// `current` no longer declares.
+///
+/// Returns an empty [`ObsoleteTokens`] if no token names were removed,
+/// without walking any accounts or roles.
+#[must_use]
+pub fn revoke_obsolete_tokens(
+    previous: &PermissionTokenSchemaSnapshot,
+    current: &PermissionTokenSchemaSnapshot,
+    wsv: &WorldStateView,
+) -> ObsoleteTokens {
+    let removed: BTreeSet<&Name> = previous
+        .keys()
+        .filter(|name| !current.contains_key(*name))
+        .collect();
+
+    if removed.is_empty() {
+        return ObsoleteTokens::default();
+    }
+
+    let revocations = wsv
+        .domains()
+        .values()
+        .flat_map(Domain::accounts)
+        .flat_map(|account| {
+            account
+                .permissions()
+                .filter(|token| removed.contains(token.name()))
+                .cloned()
+                .map(|token| RevokeBox::new(token, account.id().clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    let affected_roles = wsv
+        .roles()
+        .iter()
+        .filter(|entry| {
+            entry
+                .value()
+                .permissions()
+                .any(|token| removed.contains(token.name()))
+        })
+        .map(|entry| entry.key().clone())
+        .collect();
+
+    ObsoleteTokens {
+        revocations,
+        affected_roles,
+    }
+}
@@ -0,0 +1,149 @@
+//! Module with permissions for a domain's metadata-limit override keys.
+
+use iroha_data_model::domain::IDENT_LENGTH_LIMITS_OVERRIDE_KEY;
+
+use super::*;
+use crate::public_blockchain::key_value::Expiration;
+
+impl HasValueType for DomainId {
+    fn value_type() -> ValueType {
+        ValueType::Id
+    }
+}
+
+declare_token!(
+    /// Can set `domain_id`'s reserved metadata-limit override keys (see
+    /// [`IDENT_LENGTH_LIMITS_OVERRIDE_KEY`]).
+    CanSetDomainMetadataLimits {
+        /// Domain id.
+        domain_id ("domain_id"): DomainId,
+        /// Block height from which this grant stops being honoured.
+        expires_at ("expires_at"): Expiration,
+    },
+    "can_set_domain_metadata_limits"
+);
+
+/// If `instruction` is a `SetKeyValue` targeting one of a [`Domain`]'s
+/// reserved metadata-limit override keys, the domain and the raw value
+/// being set. `Ok(None)` for every other instruction, so both validators
+/// below can `?`-propagate evaluation failures and otherwise no-op.
+fn reserved_limits_key_target(
+    instruction: &Instruction,
+    wsv: &WorldStateView,
+) -> std::result::Result<Option<(DomainId, Value)>, String> {
+    let set_kv = if let Instruction::SetKeyValue(set_kv) = instruction {
+        set_kv
+    } else {
+        return Ok(None);
+    };
+    let object_id = set_kv
+        .object_id
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?;
+    let domain_id: DomainId = if let IdBox::DomainId(domain_id) = object_id {
+        domain_id
+    } else {
+        return Ok(None);
+    };
+    let key: Name = set_kv
+        .key
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?
+        .try_into()
+        .map_err(|_| "Key is not a Name.".to_owned())?;
+
+    if key.as_ref() != IDENT_LENGTH_LIMITS_OVERRIDE_KEY {
+        return Ok(None);
+    }
+
+    let value = set_kv
+        .value
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?;
+    Ok(Some((domain_id, value)))
+}
+
+/// Checks that setting a domain's [`IDENT_LENGTH_LIMITS_OVERRIDE_KEY`]
+/// never widens the limit past [`Configuration::ident_length_limits`],
+/// the global config's enforced ceiling - a domain may only narrow it for
+/// its own subtree.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct DomainMetadataLimitsWithinGlobalCeiling;
+
+impl_from_item_for_instruction_validator_box!(DomainMetadataLimitsWithinGlobalCeiling);
+
+impl IsAllowed<Instruction> for DomainMetadataLimitsWithinGlobalCeiling {
+    fn check(
+        &self,
+        _authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let Some((_domain_id, value)) = reserved_limits_key_target(instruction, wsv)? else {
+            return Ok(());
+        };
+
+        let raw = if let Value::String(raw) = value {
+            raw
+        } else {
+            return Err("Metadata-limit override must be a string.".to_owned().into());
+        };
+        let (min, max) = raw
+            .split_once(',')
+            .ok_or_else(|| "Metadata-limit override must be formatted as `\"min,max\"`.".to_owned())?;
+        let min: u32 = min
+            .parse()
+            .map_err(|_| "Metadata-limit override minimum is not a number.".to_owned())?;
+        let max: u32 = max
+            .parse()
+            .map_err(|_| "Metadata-limit override maximum is not a number.".to_owned())?;
+
+        let ceiling = wsv.config.ident_length_limits;
+        if min < ceiling.min() || max > ceiling.max() {
+            return Err("Domain metadata-limit override may only narrow, never widen, \
+                         the global config's ceiling."
+                .to_owned()
+                .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that only an account holding an unexpired
+/// [`CanSetDomainMetadataLimits`] grant for the target domain can set its
+/// reserved metadata-limit override keys.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct DomainMetadataLimitsRequireToken;
+
+impl_from_item_for_instruction_validator_box!(DomainMetadataLimitsRequireToken);
+
+impl IsAllowed<Instruction> for DomainMetadataLimitsRequireToken {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let Some((domain_id, _value)) = reserved_limits_key_target(instruction, wsv)? else {
+            return Ok(());
+        };
+
+        let height = wsv.height();
+        let holds_token = wsv
+            .map_account(authority, |account| wsv.account_permission_tokens(account))
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter_map(|token| CanSetDomainMetadataLimits::try_from(token).ok())
+            .any(|token| token.domain_id == domain_id && !token.expires_at.has_lapsed(height));
+
+        if !holds_token {
+            return Err("Setting a domain's metadata-limit override keys requires an \
+                         unexpired `CanSetDomainMetadataLimits` grant for that domain."
+                .to_owned()
+                .into());
+        }
+
+        Ok(())
+    }
+}
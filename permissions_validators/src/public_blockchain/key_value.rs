@@ -4,8 +4,29 @@ use iroha_data_model::asset::DefinitionId;
 
 use super::*;
 
+impl HasValueType for AssetId {
+    fn value_type() -> ValueType {
+        ValueType::Id
+    }
+}
+
+impl HasValueType for AccountId {
+    fn value_type() -> ValueType {
+        ValueType::Id
+    }
+}
+
+impl HasValueType for DefinitionId {
+    fn value_type() -> ValueType {
+        ValueType::Id
+    }
+}
+
 declare_token!(
     /// Can set key value in user's assets permission.
+    #[deprecated(note = "Superseded by `CanModifyUserAssetsMetadata`, which authorizes both \
+                          `SetKeyValue` and `RemoveKeyValue` in one grant. Kept so that \
+                          already-issued grants of this token keep working.")]
     CanSetKeyValueInUserAssets {
         /// Asset id.
         asset_id ("asset_id"): AssetId,
@@ -15,6 +36,9 @@ declare_token!(
 
 declare_token!(
     /// Can remove key value in user's assets permission.
+    #[deprecated(note = "Superseded by `CanModifyUserAssetsMetadata`, which authorizes both \
+                          `SetKeyValue` and `RemoveKeyValue` in one grant. Kept so that \
+                          already-issued grants of this token keep working.")]
     CanRemoveKeyValueInUserAssets {
         /// Asset id
         asset_id ("asset_id"): AssetId,
@@ -24,6 +48,9 @@ declare_token!(
 
 declare_token!(
     /// Can set key value in user metadata.
+    #[deprecated(note = "Superseded by `CanModifyAccountMetadata`, which authorizes both \
+                          `SetKeyValue` and `RemoveKeyValue` in one grant. Kept so that \
+                          already-issued grants of this token keep working.")]
     CanSetKeyValueInUserMetadata {
         /// Account id.
         account_id ("account_id"): AccountId,
@@ -33,6 +60,9 @@ declare_token!(
 
 declare_token!(
     /// Can remove key value in user metadata.
+    #[deprecated(note = "Superseded by `CanModifyAccountMetadata`, which authorizes both \
+                          `SetKeyValue` and `RemoveKeyValue` in one grant. Kept so that \
+                          already-issued grants of this token keep working.")]
     CanRemoveKeyValueInUserMetadata {
         /// Account id.
         account_id ("account_id"): AccountId,
@@ -42,6 +72,9 @@ declare_token!(
 
 declare_token!(
     /// Can set key value in the corresponding asset definition.
+    #[deprecated(note = "Superseded by `CanModifyAssetDefinitionMetadata`, which authorizes \
+                          both `SetKeyValue` and `RemoveKeyValue` in one grant. Kept so that \
+                          already-issued grants of this token keep working.")]
     CanSetKeyValueInAssetDefinition {
         /// Asset definition id.
         asset_definition_id ("asset_definition_id"): DefinitionId,
@@ -51,6 +84,9 @@ declare_token!(
 
 declare_token!(
     /// Can remove key value in the corresponding asset definition.
+    #[deprecated(note = "Superseded by `CanModifyAssetDefinitionMetadata`, which authorizes \
+                          both `SetKeyValue` and `RemoveKeyValue` in one grant. Kept so that \
+                          already-issued grants of this token keep working.")]
     CanRemoveKeyValueInAssetDefinition {
         /// Asset definition id.
         asset_definition_id ("asset_definition_id"): DefinitionId,
@@ -58,6 +94,232 @@ declare_token!(
     "can_remove_key_value_in_asset_definition"
 );
 
+declare_token!(
+    /// Can set or remove key value in user's assets permission. Consolidates
+    /// the deprecated `CanSetKeyValueInUserAssets` / `CanRemoveKeyValueInUserAssets`
+    /// pair into a single grant covering both instructions.
+    CanModifyUserAssetsMetadata {
+        /// Asset id.
+        asset_id ("asset_id"): AssetId,
+        /// Block height from which this grant stops being honoured.
+        expires_at ("expires_at"): Expiration,
+    },
+    "can_modify_user_assets_metadata"
+);
+
+/// Which key(s) within a metadata store a grant of
+/// [`CanModifyAccountMetadata`] / [`CanModifyAssetDefinitionMetadata`]
+/// covers. `Any` is today's whole-store access; `Only` restricts the grant
+/// to a single key, e.g. to delegate "set only `kyc_status` on my account"
+/// without exposing the rest of the store.
+///
+/// Encoded as a [`Value::String`]: the empty string stands for `Any`
+/// (no single key is a valid [`Name`]), anything else is the scoped key's
+/// name, so [`declare_token`]'s blanket `Into<Value>`/`TryFrom<Value>`
+/// plumbing keeps working unchanged.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, IntoSchema)]
+pub enum KeyScope {
+    /// Every key in the store.
+    Any,
+    /// Exactly this key.
+    Only(Name),
+}
+
+impl From<KeyScope> for Value {
+    fn from(scope: KeyScope) -> Self {
+        match scope {
+            KeyScope::Any => Value::String(String::new()),
+            KeyScope::Only(key) => Value::String(key.to_string()),
+        }
+    }
+}
+
+impl KeyScope {
+    /// Whether a grant carrying this scope covers `key`.
+    pub fn allows(&self, key: &Name) -> bool {
+        match self {
+            KeyScope::Any => true,
+            KeyScope::Only(scoped_key) => scoped_key == key,
+        }
+    }
+}
+
+impl TryFrom<Value> for KeyScope {
+    type Error = String;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        match value {
+            Value::String(key) if key.is_empty() => Ok(KeyScope::Any),
+            Value::String(key) => key
+                .parse()
+                .map(KeyScope::Only)
+                .map_err(|e| format!("Not a valid key name: {e}")),
+            _ => Err("Expected a `KeyScope`-encoded string value.".to_owned()),
+        }
+    }
+}
+
+impl HasValueType for KeyScope {
+    fn value_type() -> ValueType {
+        ValueType::String
+    }
+}
+
+/// When a grant of one of this module's consolidated `CanModify*` tokens
+/// stops being honoured, borrowing the expiring-approval idea from NFT
+/// permit systems: a delegated grant lapses on its own once the chain
+/// reaches `AtHeight`, instead of requiring an explicit `Revoke`.
+///
+/// Encoded as a [`Value::U128`]: `0` stands for `Never` (genesis is never a
+/// meaningful expiry height), any other value is the expiry block height.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, Serialize, Deserialize, IntoSchema)]
+pub enum Expiration {
+    /// Grant never expires on its own.
+    Never,
+    /// Grant stops being honoured once the chain reaches this block height.
+    AtHeight(u64),
+}
+
+impl Expiration {
+    /// Whether a grant carrying this expiration has already lapsed at
+    /// `current_height` (i.e. is no longer honoured).
+    pub fn has_lapsed(&self, current_height: u64) -> bool {
+        match self {
+            Expiration::Never => false,
+            Expiration::AtHeight(height) => current_height >= *height,
+        }
+    }
+}
+
+impl From<Expiration> for Value {
+    fn from(expiration: Expiration) -> Self {
+        match expiration {
+            Expiration::Never => Value::U128(0),
+            Expiration::AtHeight(height) => Value::U128(u128::from(height)),
+        }
+    }
+}
+
+impl TryFrom<Value> for Expiration {
+    type Error = String;
+
+    fn try_from(value: Value) -> std::result::Result<Self, Self::Error> {
+        match value {
+            Value::U128(0) => Ok(Expiration::Never),
+            Value::U128(height) => u64::try_from(height)
+                .map(Expiration::AtHeight)
+                .map_err(|_| "Expiry height does not fit in a u64.".to_owned()),
+            _ => Err("Expected an `Expiration`-encoded U128 value.".to_owned()),
+        }
+    }
+}
+
+impl HasValueType for Expiration {
+    fn value_type() -> ValueType {
+        ValueType::U128
+    }
+}
+
+declare_token!(
+    /// Can set or remove key value in user metadata. Consolidates the
+    /// deprecated `CanSetKeyValueInUserMetadata` / `CanRemoveKeyValueInUserMetadata`
+    /// pair into a single grant covering both instructions.
+    CanModifyAccountMetadata {
+        /// Account id.
+        account_id ("account_id"): AccountId,
+        /// Which key(s) of the account's metadata this grant covers.
+        key ("key"): KeyScope,
+        /// Block height from which this grant stops being honoured.
+        expires_at ("expires_at"): Expiration,
+    },
+    "can_modify_account_metadata"
+);
+
+declare_token!(
+    /// Can set or remove key value in the corresponding asset definition.
+    /// Consolidates the deprecated `CanSetKeyValueInAssetDefinition` /
+    /// `CanRemoveKeyValueInAssetDefinition` pair into a single grant covering
+    /// both instructions.
+    CanModifyAssetDefinitionMetadata {
+        /// Asset definition id.
+        asset_definition_id ("asset_definition_id"): DefinitionId,
+        /// Which key(s) of the asset definition's metadata this grant covers.
+        key ("key"): KeyScope,
+        /// Block height from which this grant stops being honoured.
+        expires_at ("expires_at"): Expiration,
+    },
+    "can_modify_asset_definition_metadata"
+);
+
+declare_token!(
+    /// Can set or remove key value in every asset owned by `account_id`, so a
+    /// delegator holding many assets doesn't have to grant one
+    /// [`CanModifyUserAssetsMetadata`] per asset.
+    CanModifyAllAssetsMetadata {
+        /// The account whose assets this grant covers.
+        account_id ("account_id"): AccountId,
+        /// Block height from which this grant stops being honoured.
+        expires_at ("expires_at"): Expiration,
+    },
+    "can_modify_all_assets_metadata"
+);
+
+declare_token!(
+    /// Can set or remove key value in every asset definition registered by
+    /// `registrant_id`, so a delegator who registered many definitions
+    /// doesn't have to grant one [`CanModifyAssetDefinitionMetadata`] per
+    /// definition.
+    CanModifyAllAssetDefinitionsMetadata {
+        /// The account whose registered asset definitions this grant covers.
+        registrant_id ("registrant_id"): AccountId,
+        /// Block height from which this grant stops being honoured.
+        expires_at ("expires_at"): Expiration,
+    },
+    "can_modify_all_asset_definitions_metadata"
+);
+
+#[allow(deprecated)]
+impl From<CanSetKeyValueInUserAssets> for CanModifyUserAssetsMetadata {
+    fn from(token: CanSetKeyValueInUserAssets) -> Self {
+        Self::new(token.asset_id, Expiration::Never)
+    }
+}
+
+#[allow(deprecated)]
+impl From<CanRemoveKeyValueInUserAssets> for CanModifyUserAssetsMetadata {
+    fn from(token: CanRemoveKeyValueInUserAssets) -> Self {
+        Self::new(token.asset_id, Expiration::Never)
+    }
+}
+
+#[allow(deprecated)]
+impl From<CanSetKeyValueInUserMetadata> for CanModifyAccountMetadata {
+    fn from(token: CanSetKeyValueInUserMetadata) -> Self {
+        Self::new(token.account_id, KeyScope::Any, Expiration::Never)
+    }
+}
+
+#[allow(deprecated)]
+impl From<CanRemoveKeyValueInUserMetadata> for CanModifyAccountMetadata {
+    fn from(token: CanRemoveKeyValueInUserMetadata) -> Self {
+        Self::new(token.account_id, KeyScope::Any, Expiration::Never)
+    }
+}
+
+#[allow(deprecated)]
+impl From<CanSetKeyValueInAssetDefinition> for CanModifyAssetDefinitionMetadata {
+    fn from(token: CanSetKeyValueInAssetDefinition) -> Self {
+        Self::new(token.asset_definition_id, KeyScope::Any, Expiration::Never)
+    }
+}
+
+#[allow(deprecated)]
+impl From<CanRemoveKeyValueInAssetDefinition> for CanModifyAssetDefinitionMetadata {
+    fn from(token: CanRemoveKeyValueInAssetDefinition) -> Self {
+        Self::new(token.asset_definition_id, KeyScope::Any, Expiration::Never)
+    }
+}
+
 /// Checks that account can set keys for assets only for the signer account.
 #[derive(Debug, Copy, Clone, Serialize)]
 pub struct AssetSetOnlyForSignerAccount;
@@ -606,3 +868,738 @@ impl HasToken for RemoveGrantedByAssetDefinitionOwner {
         Ok(CanRemoveKeyValueInAssetDefinition::new(object_id).into())
     }
 }
+
+/// Validator that checks `Revoke` instruction so that only the owner of the
+/// asset can revoke access previously granted to it. Mirrors
+/// [`GrantMyAssetAccessSet`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAssetAccessSet;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAssetAccessSet);
+
+impl IsRevokeAllowed for RevokeMyAssetAccessSet {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanSetKeyValueInUserAssets = extract_specialized_token(instruction, wsv)?;
+
+        if &token.asset_id.account_id != authority {
+            return Err(
+                "Asset specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the owner of the
+/// asset can revoke access previously granted to it. Mirrors
+/// [`GrantMyAssetAccessRemove`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAssetAccessRemove;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAssetAccessRemove);
+
+impl IsRevokeAllowed for RevokeMyAssetAccessRemove {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanRemoveKeyValueInUserAssets = extract_specialized_token(instruction, wsv)?;
+
+        if &token.asset_id.account_id != authority {
+            return Err(
+                "Asset specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the owner of the
+/// account can revoke access previously granted to its metadata. Mirrors
+/// [`GrantMyMetadataAccessSet`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyMetadataAccessSet;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyMetadataAccessSet);
+
+impl IsRevokeAllowed for RevokeMyMetadataAccessSet {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanSetKeyValueInUserMetadata = extract_specialized_token(instruction, wsv)?;
+        if &token.account_id != authority {
+            return Err(
+                "Account specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the owner of the
+/// account can revoke access previously granted to its metadata. Mirrors
+/// [`GrantMyMetadataAccessRemove`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyMetadataAccessRemove;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyMetadataAccessRemove);
+
+impl IsRevokeAllowed for RevokeMyMetadataAccessRemove {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanRemoveKeyValueInUserMetadata = extract_specialized_token(instruction, wsv)?;
+
+        if &token.account_id != authority {
+            return Err(
+                "Account specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the account that
+/// registered the asset definition can revoke access previously granted to
+/// it. Mirrors [`GrantMyAssetDefinitionSet`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAssetDefinitionSet;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAssetDefinitionSet);
+
+impl IsRevokeAllowed for RevokeMyAssetDefinitionSet {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanSetKeyValueInAssetDefinition = extract_specialized_token(instruction, wsv)?;
+
+        check_asset_creator_for_asset_definition(&token.asset_definition_id, authority, wsv)
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the account that
+/// registered the asset definition can revoke access previously granted to
+/// it. Mirrors [`GrantMyAssetDefinitionRemove`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAssetDefinitionRemove;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAssetDefinitionRemove);
+
+impl IsRevokeAllowed for RevokeMyAssetDefinitionRemove {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanRemoveKeyValueInAssetDefinition =
+            extract_specialized_token(instruction, wsv)?;
+
+        check_asset_creator_for_asset_definition(&token.asset_definition_id, authority, wsv)
+    }
+}
+
+/// Checks that account can set or remove keys for assets only for the
+/// signer account. Supersedes the separate [`AssetSetOnlyForSignerAccount`] /
+/// [`AssetRemoveOnlyForSignerAccount`] pair now that
+/// [`CanModifyUserAssetsMetadata`] authorizes both instruction variants in
+/// one grant.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct AssetModifyOnlyForSignerAccount;
+
+impl_from_item_for_instruction_validator_box!(AssetModifyOnlyForSignerAccount);
+
+impl IsAllowed<Instruction> for AssetModifyOnlyForSignerAccount {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let object_id = match instruction {
+            Instruction::SetKeyValue(set_kv) => &set_kv.object_id,
+            Instruction::RemoveKeyValue(rem_kv) => &rem_kv.object_id,
+            _ => return Ok(()),
+        }
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?;
+
+        match object_id {
+            IdBox::AssetId(asset_id) if &asset_id.account_id != authority => {
+                Err("Can't modify asset store from another account."
+                    .to_owned()
+                    .into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Allows setting or removing user's assets key value map from a different
+/// account if the corresponding user granted this permission token.
+/// Supersedes the separate [`SetGrantedByAssetOwner`] /
+/// [`RemoveGrantedByAssetOwner`] pair now that a single grant of
+/// [`CanModifyUserAssetsMetadata`] covers both.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct ModifyGrantedByAssetOwner;
+
+impl_from_item_for_granted_token_validator_box!(ModifyGrantedByAssetOwner);
+
+impl HasToken for ModifyGrantedByAssetOwner {
+    fn token(
+        &self,
+        _authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> std::result::Result<PermissionToken, String> {
+        let object_id = match instruction {
+            Instruction::SetKeyValue(set_kv) => &set_kv.object_id,
+            Instruction::RemoveKeyValue(rem_kv) => &rem_kv.object_id,
+            _ => return Err("Instruction is neither set nor remove key value.".to_owned()),
+        }
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?;
+        let object_id: AssetId = if let Ok(obj_id) = object_id.try_into() {
+            obj_id
+        } else {
+            return Err("Source id is not an AssetId.".to_owned());
+        };
+
+        let height = wsv.height();
+        let granted_tokens = wsv
+            .map_account(&object_id.account_id, |account| {
+                wsv.account_permission_tokens(account)
+            })
+            .map_err(|e| e.to_string())?;
+
+        if let Some(token) = granted_tokens
+            .iter()
+            .cloned()
+            .filter_map(|token| CanModifyUserAssetsMetadata::try_from(token).ok())
+            .find(|token| token.asset_id == object_id && !token.expires_at.has_lapsed(height))
+        {
+            return Ok(token.into());
+        }
+        granted_tokens
+            .into_iter()
+            .filter_map(|token| CanModifyAllAssetsMetadata::try_from(token).ok())
+            .find(|token| {
+                token.account_id == object_id.account_id && !token.expires_at.has_lapsed(height)
+            })
+            .map(Into::into)
+            .ok_or_else(|| {
+                "No unexpired `CanModifyUserAssetsMetadata`/`CanModifyAllAssetsMetadata` grant \
+                 covers this asset."
+                    .to_owned()
+            })
+    }
+}
+
+/// Validator that checks `Grant` instruction so that modify access is
+/// granted to the assets of the signer account. Supersedes the separate
+/// [`GrantMyAssetAccessSet`] / [`GrantMyAssetAccessRemove`] pair.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantMyAssetAccessModify;
+
+impl_from_item_for_grant_instruction_validator_box!(GrantMyAssetAccessModify);
+
+impl IsGrantAllowed for GrantMyAssetAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &GrantBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyUserAssetsMetadata = extract_specialized_token(instruction, wsv)?;
+
+        if &token.asset_id.account_id != authority {
+            return Err(
+                "Asset specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the owner of the
+/// asset can revoke modify access previously granted to it. Supersedes the
+/// separate [`RevokeMyAssetAccessSet`] / [`RevokeMyAssetAccessRemove`] pair.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAssetAccessModify;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAssetAccessModify);
+
+impl IsRevokeAllowed for RevokeMyAssetAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyUserAssetsMetadata = extract_specialized_token(instruction, wsv)?;
+
+        if &token.asset_id.account_id != authority {
+            return Err(
+                "Asset specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that account can set or remove keys only for the signer account.
+/// Supersedes the separate [`AccountSetOnlyForSignerAccount`] /
+/// [`AccountRemoveOnlyForSignerAccount`] pair now that
+/// [`CanModifyAccountMetadata`] authorizes both instruction variants in one
+/// grant.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct AccountModifyOnlyForSignerAccount;
+
+impl_from_item_for_instruction_validator_box!(AccountModifyOnlyForSignerAccount);
+
+impl IsAllowed<Instruction> for AccountModifyOnlyForSignerAccount {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let object_id = match instruction {
+            Instruction::SetKeyValue(set_kv) => &set_kv.object_id,
+            Instruction::RemoveKeyValue(rem_kv) => &rem_kv.object_id,
+            _ => return Ok(()),
+        }
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?;
+
+        match &object_id {
+            IdBox::AccountId(account_id) if account_id != authority => {
+                Err("Can't modify account store from another account."
+                    .to_owned()
+                    .into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Allows setting or removing user's metadata key value pairs from a
+/// different account if the corresponding user granted this permission
+/// token. Supersedes the separate [`SetGrantedByAccountOwner`] /
+/// [`RemoveGrantedByAccountOwner`] pair now that a single grant of
+/// [`CanModifyAccountMetadata`] covers both.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct ModifyGrantedByAccountOwner;
+
+impl_from_item_for_granted_token_validator_box!(ModifyGrantedByAccountOwner);
+
+impl HasToken for ModifyGrantedByAccountOwner {
+    fn token(
+        &self,
+        _authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> std::result::Result<PermissionToken, String> {
+        let (object_id_expr, key_expr) = match instruction {
+            Instruction::SetKeyValue(set_kv) => (&set_kv.object_id, &set_kv.key),
+            Instruction::RemoveKeyValue(rem_kv) => (&rem_kv.object_id, &rem_kv.key),
+            _ => return Err("Instruction is neither set nor remove key value.".to_owned()),
+        };
+        let object_id = object_id_expr
+            .evaluate(wsv, &Context::new())
+            .map_err(|e| e.to_string())?;
+        let object_id: AccountId = if let Ok(obj_id) = object_id.try_into() {
+            obj_id
+        } else {
+            return Err("Source id is not an AccountId.".to_owned());
+        };
+        let key: Name = key_expr
+            .evaluate(wsv, &Context::new())
+            .map_err(|e| e.to_string())?
+            .try_into()
+            .map_err(|_| "Key is not a Name.".to_owned())?;
+
+        let height = wsv.height();
+        let granted_tokens = wsv
+            .map_account(&object_id, |account| wsv.account_permission_tokens(account))
+            .map_err(|e| e.to_string())?;
+        granted_tokens
+            .into_iter()
+            .filter_map(|token| CanModifyAccountMetadata::try_from(token).ok())
+            .find(|token| {
+                token.account_id == object_id
+                    && token.key.allows(&key)
+                    && !token.expires_at.has_lapsed(height)
+            })
+            .map(Into::into)
+            .ok_or_else(|| {
+                "No unexpired `CanModifyAccountMetadata` grant covers this key.".to_owned()
+            })
+    }
+}
+
+/// Validator that checks `Grant` instruction so that modify access is
+/// granted to the metadata of the signer account. Supersedes the separate
+/// [`GrantMyMetadataAccessSet`] / [`GrantMyMetadataAccessRemove`] pair.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantMyMetadataAccessModify;
+
+impl_from_item_for_grant_instruction_validator_box!(GrantMyMetadataAccessModify);
+
+impl IsGrantAllowed for GrantMyMetadataAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &GrantBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAccountMetadata = extract_specialized_token(instruction, wsv)?;
+        if &token.account_id != authority {
+            return Err(
+                "Account specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the owner of the
+/// account can revoke modify access previously granted to its metadata.
+/// Supersedes the separate [`RevokeMyMetadataAccessSet`] /
+/// [`RevokeMyMetadataAccessRemove`] pair.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyMetadataAccessModify;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyMetadataAccessModify);
+
+impl IsRevokeAllowed for RevokeMyMetadataAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAccountMetadata = extract_specialized_token(instruction, wsv)?;
+        if &token.account_id != authority {
+            return Err(
+                "Account specified in permission token is not owned by signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Checks that account can set or remove keys for asset definitions only
+/// registered by the signer account. Supersedes the separate
+/// [`AssetDefinitionSetOnlyForSignerAccount`] /
+/// [`AssetDefinitionRemoveOnlyForSignerAccount`] pair now that
+/// [`CanModifyAssetDefinitionMetadata`] authorizes both instruction variants
+/// in one grant.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct AssetDefinitionModifyOnlyForSignerAccount;
+
+impl_from_item_for_instruction_validator_box!(AssetDefinitionModifyOnlyForSignerAccount);
+
+impl IsAllowed<Instruction> for AssetDefinitionModifyOnlyForSignerAccount {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let obj_id = match instruction {
+            Instruction::SetKeyValue(set_kv) => &set_kv.object_id,
+            Instruction::RemoveKeyValue(rem_kv) => &rem_kv.object_id,
+            _ => return Ok(()),
+        }
+        .evaluate(wsv, &Context::new())
+        .map_err(|e| e.to_string())?;
+
+        let object_id: AssetDefinitionId = try_into_or_exit!(obj_id);
+        let registered_by_signer_account = wsv
+            .asset_definition_entry(&object_id)
+            .map(|asset_definition_entry| asset_definition_entry.registered_by() == authority)
+            .unwrap_or(false);
+        if !registered_by_signer_account {
+            return Err(
+                "Can't modify key value of asset definition registered by other accounts."
+                    .to_owned()
+                    .into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Allows setting or removing asset definition's metadata key value pairs
+/// from a different account if the corresponding user granted this
+/// permission token. Supersedes the separate
+/// [`SetGrantedByAssetDefinitionOwner`] / [`RemoveGrantedByAssetDefinitionOwner`]
+/// pair now that a single grant of [`CanModifyAssetDefinitionMetadata`]
+/// covers both.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct ModifyGrantedByAssetDefinitionOwner;
+
+impl_from_item_for_granted_token_validator_box!(ModifyGrantedByAssetDefinitionOwner);
+
+impl HasToken for ModifyGrantedByAssetDefinitionOwner {
+    fn token(
+        &self,
+        _authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> std::result::Result<PermissionToken, String> {
+        let (object_id_expr, key_expr) = match instruction {
+            Instruction::SetKeyValue(set_kv) => (&set_kv.object_id, &set_kv.key),
+            Instruction::RemoveKeyValue(rem_kv) => (&rem_kv.object_id, &rem_kv.key),
+            _ => return Err("Instruction is neither set nor remove key value.".to_owned()),
+        };
+        let object_id = object_id_expr
+            .evaluate(wsv, &Context::new())
+            .map_err(|e| e.to_string())?;
+        let object_id: AssetDefinitionId = if let Ok(obj_id) = object_id.try_into() {
+            obj_id
+        } else {
+            return Err("Source id is not an AssetDefinitionId.".to_owned());
+        };
+        let key: Name = key_expr
+            .evaluate(wsv, &Context::new())
+            .map_err(|e| e.to_string())?
+            .try_into()
+            .map_err(|_| "Key is not a Name.".to_owned())?;
+
+        let registrant = wsv
+            .asset_definition_entry(&object_id)
+            .map(|asset_definition_entry| asset_definition_entry.registered_by().clone())
+            .ok_or_else(|| "Asset definition not found.".to_owned())?;
+        let height = wsv.height();
+        let granted_tokens = wsv
+            .map_account(&registrant, |account| wsv.account_permission_tokens(account))
+            .map_err(|e| e.to_string())?;
+
+        if let Some(token) = granted_tokens
+            .iter()
+            .cloned()
+            .filter_map(|token| CanModifyAssetDefinitionMetadata::try_from(token).ok())
+            .find(|token| {
+                token.asset_definition_id == object_id
+                    && token.key.allows(&key)
+                    && !token.expires_at.has_lapsed(height)
+            })
+        {
+            return Ok(token.into());
+        }
+        granted_tokens
+            .into_iter()
+            .filter_map(|token| CanModifyAllAssetDefinitionsMetadata::try_from(token).ok())
+            .find(|token| {
+                token.registrant_id == registrant && !token.expires_at.has_lapsed(height)
+            })
+            .map(Into::into)
+            .ok_or_else(|| {
+                "No unexpired `CanModifyAssetDefinitionMetadata`/\
+                 `CanModifyAllAssetDefinitionsMetadata` grant covers this key."
+                    .to_owned()
+            })
+    }
+}
+
+/// Validator that checks `Grant` instruction so that modify access is
+/// granted to the asset definition registered by the signer account.
+/// Supersedes the separate [`GrantMyAssetDefinitionSet`] /
+/// [`GrantMyAssetDefinitionRemove`] pair.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantMyAssetDefinitionModify;
+
+impl_from_item_for_grant_instruction_validator_box!(GrantMyAssetDefinitionModify);
+
+impl IsGrantAllowed for GrantMyAssetDefinitionModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &GrantBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAssetDefinitionMetadata = extract_specialized_token(instruction, wsv)?;
+
+        check_asset_creator_for_asset_definition(&token.asset_definition_id, authority, wsv)
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the account that
+/// registered the asset definition can revoke modify access previously
+/// granted to it. Supersedes the separate [`RevokeMyAssetDefinitionSet`] /
+/// [`RevokeMyAssetDefinitionRemove`] pair.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAssetDefinitionModify;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAssetDefinitionModify);
+
+impl IsRevokeAllowed for RevokeMyAssetDefinitionModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAssetDefinitionMetadata = extract_specialized_token(instruction, wsv)?;
+
+        check_asset_creator_for_asset_definition(&token.asset_definition_id, authority, wsv)
+    }
+}
+
+/// Validator that checks `Grant` instruction so that modify access is
+/// granted to every asset of the signer account. Pairs with
+/// [`CanModifyAllAssetsMetadata`] as the wildcard counterpart of
+/// [`GrantMyAssetAccessModify`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantMyAllAssetsAccessModify;
+
+impl_from_item_for_grant_instruction_validator_box!(GrantMyAllAssetsAccessModify);
+
+impl IsGrantAllowed for GrantMyAllAssetsAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &GrantBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAllAssetsMetadata = extract_specialized_token(instruction, wsv)?;
+
+        if &token.account_id != authority {
+            return Err(
+                "Account specified in permission token is not the signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the signer
+/// account can revoke wildcard modify access previously granted over all
+/// of its assets.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAllAssetsAccessModify;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAllAssetsAccessModify);
+
+impl IsRevokeAllowed for RevokeMyAllAssetsAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAllAssetsMetadata = extract_specialized_token(instruction, wsv)?;
+
+        if &token.account_id != authority {
+            return Err(
+                "Account specified in permission token is not the signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Validator that checks `Grant` instruction so that modify access is
+/// granted to every asset definition registered by the signer account.
+/// Pairs with [`CanModifyAllAssetDefinitionsMetadata`] as the wildcard
+/// counterpart of [`GrantMyAssetDefinitionModify`].
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct GrantMyAllAssetDefinitionsAccessModify;
+
+impl_from_item_for_grant_instruction_validator_box!(GrantMyAllAssetDefinitionsAccessModify);
+
+impl IsGrantAllowed for GrantMyAllAssetDefinitionsAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &GrantBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAllAssetDefinitionsMetadata =
+            extract_specialized_token(instruction, wsv)?;
+
+        if &token.registrant_id != authority {
+            return Err(
+                "Account specified in permission token is not the signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Validator that checks `Revoke` instruction so that only the signer
+/// account can revoke wildcard modify access previously granted over all
+/// asset definitions it registered.
+#[derive(Debug, Copy, Clone, Serialize)]
+pub struct RevokeMyAllAssetDefinitionsAccessModify;
+
+impl_from_item_for_revoke_instruction_validator_box!(RevokeMyAllAssetDefinitionsAccessModify);
+
+impl IsRevokeAllowed for RevokeMyAllAssetDefinitionsAccessModify {
+    fn check(
+        &self,
+        authority: &AccountId,
+        instruction: &RevokeBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        let token: CanModifyAllAssetDefinitionsMetadata =
+            extract_specialized_token(instruction, wsv)?;
+
+        if &token.registrant_id != authority {
+            return Err(
+                "Account specified in permission token is not the signer."
+                    .to_owned()
+                    .into(),
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+//! A single combined validator, so policy enforcement is one call instead
+//! of hand-walking the `IsInstructionAllowedBoxed`/`IsQueryAllowedBoxed`
+//! chain produced by the `impl_from_item_for_*` macros.
+
+use std::sync::{Arc, RwLock};
+
+use super::*;
+
+/// One instruction-validation entry point and one query-validation entry
+/// point, built once from whatever combination of the crate's predefined
+/// validators (and any custom ones) a deployment needs.
+pub struct Validator {
+    instruction: IsInstructionAllowedBoxed,
+    query: IsQueryAllowedBoxed,
+}
+
+impl Validator {
+    /// Combine `instruction` and `query` into a single [`Validator`].
+    pub fn new(
+        instruction: impl Into<IsInstructionAllowedBoxed>,
+        query: impl Into<IsQueryAllowedBoxed>,
+    ) -> Self {
+        Self {
+            instruction: instruction.into(),
+            query: query.into(),
+        }
+    }
+
+    /// Check `instruction` against this validator's combined instruction
+    /// policy.
+    pub fn validate_instruction(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        self.instruction.check(authority, instruction, wsv)
+    }
+
+    /// Check `query` against this validator's combined query policy.
+    pub fn validate_query(
+        &self,
+        authority: &AccountId,
+        query: &QueryBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        self.query.check(authority, query, wsv)
+    }
+}
+
+/// Holds the [`Validator`] currently in effect behind a lock, so a
+/// deployment's whole permission policy can be replaced atomically at
+/// runtime instead of requiring a recompile and redeploy of the peer.
+pub struct UpgradableValidator(RwLock<Arc<Validator>>);
+
+impl UpgradableValidator {
+    /// Start out enforcing `validator`.
+    #[must_use]
+    pub fn new(validator: Validator) -> Self {
+        Self(RwLock::new(Arc::new(validator)))
+    }
+
+    /// Atomically swap in `validator` as the one now in effect. In-flight
+    /// calls to [`validate_instruction`](Self::validate_instruction) or
+    /// [`validate_query`](Self::validate_query) that already grabbed the
+    /// previous validator finish against it; every call afterwards sees
+    /// `validator`.
+    pub fn upgrade(&self, validator: Validator) {
+        let mut current = self.0.write().expect("lock poisoned");
+        *current = Arc::new(validator);
+    }
+
+    fn current(&self) -> Arc<Validator> {
+        Arc::clone(&self.0.read().expect("lock poisoned"))
+    }
+
+    /// Check `instruction` against the validator currently in effect.
+    pub fn validate_instruction(
+        &self,
+        authority: &AccountId,
+        instruction: &Instruction,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        self.current().validate_instruction(authority, instruction, wsv)
+    }
+
+    /// Check `query` against the validator currently in effect.
+    pub fn validate_query(
+        &self,
+        authority: &AccountId,
+        query: &QueryBox,
+        wsv: &WorldStateView,
+    ) -> Result<()> {
+        self.current().validate_query(authority, query, wsv)
+    }
+}
@@ -46,6 +46,8 @@ pub mod error {
         ExpectedJson,
         /// Cannot encode unsupported version from Parity SCALE to JSON
         UnsupportedScaleEncode,
+        /// Cannot convert between MessagePack and another format
+        UnsupportedMsgPackEncode,
         /// JSON (de)serialization issue
         Serde,
         /// Parity SCALE (de)serialization issue
@@ -56,6 +58,26 @@ pub mod error {
         UnsupportedVersion(Box<UnsupportedVersion>),
         /// Buffer is not empty after decoding. Returned by `decode_all_versioned()`
         ExtraBytesLeft(u64),
+        /// The content digest carried alongside a [`super::RawVersioned`]
+        /// doesn't match the content itself: the payload is corrupt or was
+        /// truncated on the wire, as opposed to merely being an unknown
+        /// version.
+        ContentCorrupt {
+            /// Digest the sender declared.
+            expected: Vec<u8>,
+            /// Digest actually computed over the received content.
+            actual: Vec<u8>,
+        },
+    }
+
+    impl Error {
+        /// Builds an [`Error::UnsupportedVersion`] for `version`/`raw`, boxing
+        /// the payload. Exists so macros expanding in downstream crates (e.g.
+        /// `match_version!`) don't need their own `alloc`/`std` `Box` import.
+        #[must_use]
+        pub fn unsupported_version(version: u8, raw: super::RawVersioned) -> Self {
+            Self::UnsupportedVersion(Box::new(super::UnsupportedVersion::new(version, raw)))
+        }
     }
 
     #[cfg(feature = "json")]
@@ -89,15 +111,25 @@ pub mod error {
                 Self::UnsupportedScaleEncode => {
                     "Cannot encode unsupported version from SCALE to JSON".to_owned()
                 }
+                Self::UnsupportedMsgPackEncode => {
+                    "Cannot convert between MessagePack and another format".to_owned()
+                }
                 #[cfg(feature = "json")]
                 Self::Serde => "JSON (de)serialization issue".to_owned(),
                 #[cfg(feature = "scale")]
                 Self::ParityScale => "Parity SCALE (de)serialization issue".to_owned(),
                 Self::ParseInt => "Issue with parsing integers".to_owned(),
-                Self::UnsupportedVersion(v) => {
-                    format!("Input version {} is unsupported", v.version)
-                }
+                Self::UnsupportedVersion(v) => match v.version_number {
+                    Some(number) => format!(
+                        "Input version {}.{} is unsupported",
+                        number.major, number.minor
+                    ),
+                    None => format!("Input version {} is unsupported", v.version),
+                },
                 Self::ExtraBytesLeft(n) => format!("Buffer contains {n} bytes after decoding"),
+                Self::ContentCorrupt { expected, actual } => format!(
+                    "Content digest mismatch, expected {expected:?}, got {actual:?}: payload is corrupt or truncated"
+                ),
             };
 
             write!(f, "{}", msg)
@@ -128,6 +160,28 @@ pub mod error {
     pub type Result<T, E = Error> = core::result::Result<T, E>;
 }
 
+/// A `major.minor` version pair, for containers where a minor bump is
+/// backward-compatible (only adds optional fields) but a major bump is
+/// breaking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, IntoSchema)]
+#[cfg_attr(feature = "scale", derive(Encode, Decode))]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+pub struct VersionNumber {
+    /// Breaking version component.
+    pub major: u16,
+    /// Backward-compatible version component.
+    pub minor: u16,
+}
+
+impl VersionNumber {
+    /// Constructs a [`VersionNumber`].
+    #[must_use]
+    #[inline]
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
 /// General trait describing if this is a versioned container.
 pub trait Version {
     /// Version of the data contained inside.
@@ -140,6 +194,100 @@ pub trait Version {
     fn is_supported(&self) -> bool {
         Self::supported_versions().contains(&self.version())
     }
+
+    /// This container's version as a `(major, minor)` pair, for containers
+    /// that use that scheme instead of the plain `u8` returned by
+    /// [`Version::version`]. Defaults to `None`.
+    fn version_number(&self) -> Option<VersionNumber> {
+        None
+    }
+
+    /// The highest known minor for each major this container supports, in
+    /// no particular order. A payload is supported if its major matches one
+    /// of these entries and its minor is no greater than the paired value:
+    /// payloads of an older minor of a supported major are read, since
+    /// minor bumps only add optional fields; a minor above the paired value
+    /// is rejected.
+    fn supported_version_numbers() -> &'static [VersionNumber] {
+        &[]
+    }
+
+    /// Like [`Version::is_supported`], but for the `(major, minor)` scheme
+    /// reported by [`Version::version_number`].
+    fn is_version_number_supported(&self) -> bool {
+        self.version_number().map_or(false, |version| {
+            Self::supported_version_numbers()
+                .iter()
+                .any(|max| max.major == version.major && version.minor <= max.minor)
+        })
+    }
+}
+
+/// Declares how a version struct is derived from the one immediately
+/// preceding it in its migration chain, so a decoder can transparently lift
+/// an older-but-supported payload up to the latest version instead of
+/// rejecting it.
+///
+/// The chain formed by following [`Migrate::Previous`] back from the latest
+/// version must be contiguous down to `Self::supported_versions().start`:
+/// there must be exactly one [`Migrate`] impl per version in that range, and
+/// a decoder is expected to apply them in order, oldest first, until it
+/// reaches the version it decoded. A version below
+/// `supported_versions().start` is never migrated and still yields
+/// [`UnsupportedVersion`] — this trait only extends what happens to versions
+/// that are already considered supported.
+pub trait Migrate: Version {
+    /// The version immediately preceding `Self` in the migration chain.
+    type Previous;
+
+    /// Upgrades `prev` to `Self`.
+    fn migrate(prev: Self::Previous) -> Self;
+}
+
+/// Reachable from `Self` by repeatedly applying [`Migrate::migrate`] all the
+/// way to [`Self::Latest`], so a decoder can walk an entire chain in one
+/// call instead of hand-chaining `migrate` once per version. Implemented by
+/// [`migrate_chain!`] for every version but the latest in a chain; the
+/// latest implements it as the identity.
+pub trait MigrateToLatest {
+    /// The newest version in this chain.
+    type Latest;
+
+    /// Walks forward from `Self` to [`Self::Latest`].
+    fn migrate_to_latest(self) -> Self::Latest;
+}
+
+/// Registers a chain of versions, oldest first, as reachable from one
+/// another via [`Migrate`], and derives [`MigrateToLatest`] for every
+/// version in it - so [`match_version`]'s generated `decode_and_migrate`
+/// (or any other caller) can lift a decoded value straight to the chain's
+/// latest version.
+///
+/// Each consecutive pair must already have a [`Migrate`] impl (the later
+/// version implementing `Migrate<Previous = `earlier version`>`); this
+/// macro only composes those into `MigrateToLatest`, it doesn't write the
+/// per-step `migrate` bodies.
+#[macro_export]
+macro_rules! migrate_chain {
+    ([$last:ty]) => {
+        impl $crate::MigrateToLatest for $last {
+            type Latest = $last;
+
+            fn migrate_to_latest(self) -> Self::Latest {
+                self
+            }
+        }
+    };
+    ([$head:ty, $next:ty $(, $rest:ty)* $(,)?]) => {
+        impl $crate::MigrateToLatest for $head {
+            type Latest = <$next as $crate::MigrateToLatest>::Latest;
+
+            fn migrate_to_latest(self) -> Self::Latest {
+                $crate::MigrateToLatest::migrate_to_latest(<$next as $crate::Migrate>::migrate(self))
+            }
+        }
+        $crate::migrate_chain!([$next $(, $rest)*]);
+    };
 }
 
 /// Structure describing a container content which version is not supported.
@@ -157,6 +305,9 @@ pub trait Version {
 pub struct UnsupportedVersion {
     /// Version of the content.
     pub version: u8,
+    /// `(major, minor)` version of the content, for containers that use
+    /// that scheme. `None` for containers versioned by a plain `u8` only.
+    pub version_number: Option<VersionNumber>,
     /// Raw content.
     pub raw: RawVersioned,
 }
@@ -166,7 +317,23 @@ impl UnsupportedVersion {
     #[must_use]
     #[inline]
     pub const fn new(version: u8, raw: RawVersioned) -> Self {
-        Self { version, raw }
+        Self {
+            version,
+            version_number: None,
+            raw,
+        }
+    }
+
+    /// Constructs [`UnsupportedVersion`] for a container versioned by a
+    /// `(major, minor)` pair.
+    #[must_use]
+    #[inline]
+    pub const fn with_version_number(version_number: VersionNumber, raw: RawVersioned) -> Self {
+        Self {
+            version: 0,
+            version_number: Some(version_number),
+            raw,
+        }
     }
 
     /// Expected version
@@ -175,6 +342,71 @@ impl UnsupportedVersion {
     }
 }
 
+/// Identifies an overall application/release, as opposed to the struct
+/// version of one particular versioned type. Used as the key into a
+/// [`VersionMap`] when deciding what struct version to encode down to for a
+/// given peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AppVersion(pub u32);
+
+/// Maps an [`AppVersion`] to the concrete struct version each versioned type
+/// should be serialized as when talking to a peer running that release, so
+/// a node can encode *down* to whatever an older peer understands instead of
+/// always writing the latest schema.
+///
+/// [`EncodeVersioned::encode_versioned`]/[`SerializeVersioned::to_versioned_json_str`]
+/// always emit the latest struct version and do not consult this map. This
+/// module only owns the `app_version -> struct_version` table and its
+/// lookup, [`VersionMap::struct_version_for`]; nothing in this crate
+/// encodes a value down to the struct version it returns, and [`Migrate`]
+/// only converts upward.
+#[cfg(feature = "std")]
+pub mod version_map {
+    use std::{any::TypeId, collections::BTreeMap};
+
+    use super::AppVersion;
+
+    /// See [module-level docs](self).
+    #[derive(Debug, Default)]
+    pub struct VersionMap {
+        // Keyed by `AppVersion` so `struct_version_for` can binary-search
+        // down to the newest release at or below the target.
+        releases: BTreeMap<AppVersion, BTreeMap<TypeId, u8>>,
+    }
+
+    impl VersionMap {
+        /// Constructs an empty [`VersionMap`].
+        #[must_use]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Records that, as of `app_version`, `T` is serialized as
+        /// `struct_version`. Call this once per release that changed `T`'s
+        /// struct version, not once per release overall.
+        pub fn register<T: 'static>(&mut self, app_version: AppVersion, struct_version: u8) -> &mut Self {
+            self.releases
+                .entry(app_version)
+                .or_default()
+                .insert(TypeId::of::<T>(), struct_version);
+            self
+        }
+
+        /// The struct version `T` should be encoded as for `target`: the
+        /// value registered at the newest `app_version <= target`, or
+        /// `None` if `T` has no entry at or before `target`.
+        #[must_use]
+        pub fn struct_version_for<T: 'static>(&self, target: AppVersion) -> Option<u8> {
+            self.releases
+                .range(..=target)
+                .rev()
+                .find_map(|(_, types)| types.get(&TypeId::of::<T>()).copied())
+        }
+    }
+}
+#[cfg(feature = "std")]
+pub use version_map::VersionMap;
+
 /// Raw versioned content, serialized.
 #[derive(Debug, Clone, IntoSchema)]
 #[cfg_attr(feature = "scale", derive(Encode, Decode))]
@@ -184,6 +416,55 @@ pub enum RawVersioned {
     Json(String),
     /// In Parity Scale Codec format.
     ScaleBytes(Vec<u8>),
+    /// In MessagePack format, struct-map encoded (field names preserved).
+    #[cfg(feature = "msgpack")]
+    MsgPack(Vec<u8>),
+}
+
+impl RawVersioned {
+    /// The content bytes, regardless of format, so a digest can be computed
+    /// or checked the same way for every variant.
+    #[must_use]
+    pub fn content_bytes(&self) -> &[u8] {
+        match self {
+            Self::Json(json) => json.as_bytes(),
+            Self::ScaleBytes(bytes) => bytes,
+            #[cfg(feature = "msgpack")]
+            Self::MsgPack(bytes) => bytes,
+        }
+    }
+
+    /// A 32-byte integrity digest of [`Self::content_bytes`].
+    ///
+    /// Computed with `sha2::Sha256`. Checked with
+    /// [`RawVersioned::verify_digest`], a corrupted/truncated payload is
+    /// reported as [`error::Error::ContentCorrupt`] instead of a confusing
+    /// "unsupported version" or raw SCALE/JSON decode error. No encoder or
+    /// decoder in this crate writes or checks the digest; the caller
+    /// passes it to [`RawVersioned::verify_digest`].
+    #[must_use]
+    pub fn digest(&self) -> Vec<u8> {
+        use sha2::{Digest, Sha256};
+
+        Sha256::digest(self.content_bytes()).to_vec()
+    }
+
+    /// Checks `self`'s content against an `expected` digest that travelled
+    /// alongside it on the wire.
+    ///
+    /// # Errors
+    /// [`error::Error::ContentCorrupt`] if the digests don't match.
+    pub fn verify_digest(&self, expected: &[u8]) -> error::Result<()> {
+        let actual = self.digest();
+        if actual == expected {
+            Ok(())
+        } else {
+            Err(error::Error::ContentCorrupt {
+                expected: expected.to_vec(),
+                actual,
+            })
+        }
+    }
 }
 
 /// Scale related versioned (de)serialization traits.
@@ -255,6 +536,106 @@ pub mod scale {
             res
         }};
     }
+
+    /// Synthesizes a `Versioned` wrapper enum, its [`Version`] impl, and the
+    /// tag-to-type decode dispatch from a single `tag => Type` list, so the
+    /// enum, the supported range, and the decode `match` can't drift out of
+    /// sync with each other.
+    ///
+    /// The leading byte of the input is read as the version tag; the rest is
+    /// SCALE-decoded into the matching arm's type. A tag with no matching arm
+    /// yields [`crate::error::Error::UnsupportedVersion`] with the original
+    /// bytes preserved in [`crate::RawVersioned::ScaleBytes`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// match_version! {
+    ///     pub enum Versioned {
+    ///         1 => V1,
+    ///         2 => V2,
+    ///     }
+    /// }
+    /// ```
+    #[macro_export]
+    macro_rules! match_version {
+        (
+            $(#[$meta:meta])*
+            $vis:vis enum $enum_name:ident {
+                $($tag:literal => $ty:ident),+ $(,)?
+            }
+        ) => {
+            $(#[$meta])*
+            $vis enum $enum_name {
+                $(
+                    #[allow(missing_docs)]
+                    $ty($ty),
+                )+
+            }
+
+            impl $crate::Version for $enum_name {
+                fn version(&self) -> u8 {
+                    match self {
+                        $(Self::$ty(_) => $tag,)+
+                    }
+                }
+
+                fn supported_versions() -> ::core::ops::Range<u8> {
+                    const TAGS: &[u8] = &[$($tag),+];
+                    #[allow(clippy::unwrap_used)]
+                    let min = *TAGS.iter().min().unwrap();
+                    #[allow(clippy::unwrap_used)]
+                    let max = *TAGS.iter().max().unwrap();
+                    min..(max + 1)
+                }
+            }
+
+            impl $enum_name {
+                /// Reads the leading version-tag byte and decodes the rest
+                /// into the matching arm.
+                ///
+                /// # Errors
+                /// Returns [`$crate::error::Error::UnsupportedVersion`] if the
+                /// tag byte does not match any declared version, and
+                /// whatever [`$crate::error::Error`] the matching type's
+                /// `decode` returns otherwise.
+                pub fn decode_versioned(input: &[u8]) -> $crate::error::Result<Self> {
+                    let (tag, body) = input.split_first().ok_or($crate::error::Error::NotVersioned)?;
+                    match *tag {
+                        $(
+                            $tag => <$ty as $crate::Decode>::decode(&mut &body[..])
+                                .map(Self::$ty)
+                                .map_err($crate::error::Error::from),
+                        )+
+                        unknown => Err($crate::error::Error::unsupported_version(
+                            unknown,
+                            $crate::RawVersioned::ScaleBytes(input.to_vec()),
+                        )),
+                    }
+                }
+
+                /// Like [`Self::decode_versioned`], but also walks
+                /// [`$crate::Migrate`] forward from whichever version was
+                /// on the wire to the chain's latest version, via
+                /// [`$crate::MigrateToLatest`] - so a caller always gets
+                /// the newest struct back instead of matching on `Self`
+                /// itself. Every `$ty` above must have a
+                /// [`$crate::migrate_chain!`] registration ending at the
+                /// same latest type, or this fails to compile.
+                ///
+                /// # Errors
+                /// Same as [`Self::decode_versioned`].
+                pub fn decode_and_migrate<Latest>(input: &[u8]) -> $crate::error::Result<Latest>
+                where
+                    $( $ty: $crate::MigrateToLatest<Latest = Latest>, )+
+                {
+                    Self::decode_versioned(input).map(|decoded| match decoded {
+                        $(Self::$ty(v) => $crate::MigrateToLatest::migrate_to_latest(v),)+
+                    })
+                }
+            }
+        };
+    }
 }
 
 /// JSON related versioned (de)serialization traits.
@@ -289,10 +670,79 @@ pub mod json {
     }
 }
 
+/// MessagePack related versioned (de)serialization traits.
+///
+/// Unlike [`scale`], encoding is struct-map rather than positional: field
+/// names travel with the data, so an added optional field doesn't break an
+/// older decoder the way a new positional SCALE field would. For the
+/// version tag to be readable without deserializing the whole map, the
+/// `version` field must be declared first in the struct.
+#[cfg(feature = "msgpack")]
+pub mod msgpack {
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::String, vec::Vec};
+
+    use serde::{de::DeserializeOwned, Serialize};
+
+    use super::{error::Result, Version};
+
+    /// [`Decode`](parity_scale_codec::Decode) versioned analog, for
+    /// MessagePack.
+    pub trait DecodeVersionedMsgPack: DeserializeOwned + Version {
+        /// Use this function for versioned objects instead of
+        /// [`rmp_serde::from_slice`].
+        ///
+        /// # Errors
+        /// - Version is unsupported
+        /// - `input` isn't valid struct-map MessagePack for `Self`
+        fn decode_versioned_msgpack(input: &[u8]) -> Result<Self>;
+    }
+
+    /// [`Encode`](parity_scale_codec::Encode) versioned analog, for
+    /// MessagePack.
+    pub trait EncodeVersionedMsgPack: Serialize + Version {
+        /// Use this function for versioned objects instead of
+        /// [`rmp_serde::to_vec_named`]. Always struct-map encoded, so field
+        /// names are preserved on the wire.
+        fn encode_versioned_msgpack(&self) -> Vec<u8>;
+    }
+
+    /// Converts MessagePack bytes to a JSON string, for interop with
+    /// JSON-only callers. Goes through [`serde_json::Value`] rather than a
+    /// concrete type, so it works without knowing which versioned type
+    /// `input` holds.
+    ///
+    /// # Errors
+    /// [`super::error::Error::UnsupportedMsgPackEncode`] if `input` isn't
+    /// valid MessagePack; [`super::error::Error::Serde`] if the decoded
+    /// value can't be written as JSON.
+    #[cfg(feature = "json")]
+    pub fn to_json(input: &[u8]) -> Result<String> {
+        let value: serde_json::Value =
+            rmp_serde::from_slice(input).map_err(|_| super::error::Error::UnsupportedMsgPackEncode)?;
+        serde_json::to_string(&value).map_err(super::error::Error::from)
+    }
+
+    /// The inverse of [`to_json`]: converts a JSON string to struct-map
+    /// MessagePack bytes.
+    ///
+    /// # Errors
+    /// [`super::error::Error::Serde`] if `input` isn't valid JSON;
+    /// [`super::error::Error::UnsupportedMsgPackEncode`] if the decoded value
+    /// can't be re-encoded as MessagePack.
+    #[cfg(feature = "json")]
+    pub fn from_json(input: &str) -> Result<Vec<u8>> {
+        let value: serde_json::Value = serde_json::from_str(input)?;
+        rmp_serde::to_vec_named(&value).map_err(|_| super::error::Error::UnsupportedMsgPackEncode)
+    }
+}
+
 /// The prelude re-exports most commonly used traits, structs and macros from this crate.
 pub mod prelude {
     #[cfg(feature = "json")]
     pub use super::json::*;
+    #[cfg(feature = "msgpack")]
+    pub use super::msgpack::*;
     #[cfg(feature = "scale")]
     pub use super::scale::*;
     pub use super::*;
@@ -324,4 +774,145 @@ mod tests {
         assert!(!VersionedContainer(10).is_supported());
         assert!(!VersionedContainer(11).is_supported());
     }
+
+    struct VersionNumberedContainer(VersionNumber);
+
+    impl Version for VersionNumberedContainer {
+        fn version(&self) -> u8 {
+            0
+        }
+
+        fn supported_versions() -> Range<u8> {
+            0..0
+        }
+
+        fn version_number(&self) -> Option<VersionNumber> {
+            Some(self.0)
+        }
+
+        fn supported_version_numbers() -> &'static [VersionNumber] {
+            &[VersionNumber::new(1, 2), VersionNumber::new(2, 0)]
+        }
+    }
+
+    #[test]
+    fn version_number_minor_is_forward_tolerant() {
+        assert!(VersionNumberedContainer(VersionNumber::new(1, 0)).is_version_number_supported());
+        assert!(VersionNumberedContainer(VersionNumber::new(1, 2)).is_version_number_supported());
+        assert!(!VersionNumberedContainer(VersionNumber::new(1, 3)).is_version_number_supported());
+        assert!(!VersionNumberedContainer(VersionNumber::new(3, 0)).is_version_number_supported());
+    }
+
+    #[test]
+    fn digest_detects_corruption() {
+        let raw = RawVersioned::ScaleBytes(vec![1, 2, 3]);
+        let digest = raw.digest();
+        assert!(raw.verify_digest(&digest).is_ok());
+
+        let corrupted = RawVersioned::ScaleBytes(vec![1, 2, 4]);
+        match corrupted.verify_digest(&digest) {
+            Err(error::Error::ContentCorrupt { expected, actual }) => {
+                assert_eq!(expected, digest);
+                assert_ne!(actual, digest);
+            }
+            other => panic!("expected ContentCorrupt, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn version_map_picks_newest_release_at_or_below_target() {
+        struct Thing;
+
+        let mut map = VersionMap::new();
+        map.register::<Thing>(AppVersion(1), 1);
+        map.register::<Thing>(AppVersion(3), 2);
+
+        assert_eq!(map.struct_version_for::<Thing>(AppVersion(0)), None);
+        assert_eq!(map.struct_version_for::<Thing>(AppVersion(1)), Some(1));
+        assert_eq!(map.struct_version_for::<Thing>(AppVersion(2)), Some(1));
+        assert_eq!(map.struct_version_for::<Thing>(AppVersion(3)), Some(2));
+        assert_eq!(map.struct_version_for::<Thing>(AppVersion(10)), Some(2));
+    }
+
+    #[derive(Encode, Decode)]
+    struct V1(u32);
+
+    impl Version for V1 {
+        fn version(&self) -> u8 {
+            1
+        }
+
+        fn supported_versions() -> Range<u8> {
+            1..4
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    struct V2(u32);
+
+    impl Version for V2 {
+        fn version(&self) -> u8 {
+            2
+        }
+
+        fn supported_versions() -> Range<u8> {
+            1..4
+        }
+    }
+
+    impl Migrate for V2 {
+        type Previous = V1;
+
+        fn migrate(V1(value): V1) -> Self {
+            Self(value)
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    struct V3(u32);
+
+    impl Version for V3 {
+        fn version(&self) -> u8 {
+            3
+        }
+
+        fn supported_versions() -> Range<u8> {
+            1..4
+        }
+    }
+
+    impl Migrate for V3 {
+        type Previous = V2;
+
+        fn migrate(V2(value): V2) -> Self {
+            Self(value + 1)
+        }
+    }
+
+    #[test]
+    fn migration_chain_composes() {
+        let v1 = V1(41);
+        let v3 = V3::migrate(V2::migrate(v1));
+        assert_eq!(v3.0, 42);
+    }
+
+    migrate_chain!([V1, V2, V3]);
+
+    match_version! {
+        enum VersionedThing {
+            1 => V1,
+            2 => V2,
+            3 => V3,
+        }
+    }
+
+    #[test]
+    fn decode_and_migrate_walks_the_chain() {
+        let encoded = [&[1_u8][..], &V1(41).encode()[..]].concat();
+
+        let migrated: V3 = VersionedThing::decode_and_migrate(&encoded).unwrap();
+
+        assert_eq!(migrated.0, 42);
+    }
 }